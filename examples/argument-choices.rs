@@ -27,6 +27,7 @@ async fn main() {
 
     let framework = Framework::builder(http_client, Id::new(application_id), ())
         .command(multiple_selection)
+        .command(pick_size)
         .build();
 
     while let Some((_, event)) = stream.next().await {
@@ -78,3 +79,26 @@ async fn multiple_selection(
 
     Ok(())
 }
+
+#[derive(Debug, Choice)]
+enum Size {
+    #[choice(name = "Small size", value = 1)]
+    Small,
+    #[choice(name = "Medium size", value = 2, name_localizations("es-ES" = "Tamaño mediano"))]
+    Medium,
+    #[choice(name = "Large size", value = 3)]
+    Large
+}
+
+#[command]
+#[description = "Picks a size"]
+async fn pick_size(
+    ctx: &SlashContext<()>,
+    #[description = "The size to pick"] size: Size
+) -> DefaultCommandResult {
+    ctx.respond()
+        .content(format!("You picked: {size:?}"))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::env;
+use std::sync::Arc;
+use futures_util::StreamExt;
+use twilight_gateway::{stream::{self, ShardEventStream}, Config};
+use twilight_http::Client;
+use twilight_model::gateway::event::Event;
+use twilight_model::gateway::Intents;
+use twilight_model::application::interaction::application_command::InteractionMember;
+use twilight_model::channel::Message;
+use twilight_model::id::Id;
+use twilight_model::user::User;
+use vesper::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    let token = env::var("DISCORD_TOKEN").unwrap();
+    let application_id = env::var("DISCORD_APPLICATION_ID").unwrap().parse::<u64>().unwrap();
+
+    let http_client = Arc::new(Client::new(token.clone()));
+
+    let config = Config::new(token.clone(), Intents::empty());
+    let mut shards = stream::create_recommended(
+        &http_client,
+        config,
+        |_, builder| builder.build()
+    ).await.unwrap().collect::<Vec<_>>();
+
+    let mut stream = ShardEventStream::new(shards.iter_mut());
+
+    let framework = Framework::builder(http_client, Id::new(application_id), ())
+        .command(quote)
+        .command(user_info)
+        .build();
+
+    while let Some((_, event)) = stream.next().await {
+        match event {
+            Err(error) => {
+                if error.is_fatal() {
+                    eprintln!("Gateway connection fatally closed, error: {error:?}");
+                    break;
+                }
+            },
+            Ok(event) => match event {
+                Event::Ready(_) => {
+                    // We have to register the commands for them to show in discord.
+                    framework.register_global_commands().await.unwrap();
+                },
+                Event::InteractionCreate(interaction) => {
+                    framework.process(interaction.0).await;
+                },
+                _ => ()
+            }
+        }
+    }
+}
+
+#[message_command("Quote message")]
+async fn quote(ctx: &SlashContext<()>, message: Message) -> DefaultCommandResult {
+    ctx.respond()
+        .content(format!("{} said:\n> {}", message.author.name, message.content))
+        .await?;
+
+    Ok(())
+}
+
+#[user_command("User info")]
+async fn user_info(
+    ctx: &SlashContext<()>,
+    user: User,
+    // The member is only present when the command is used inside a guild.
+    member: Option<InteractionMember>
+) -> DefaultCommandResult {
+    let content = match member.and_then(|member| member.nick) {
+        Some(nick) => format!("{} is known as {} in this guild", user.name, nick),
+        None => format!("{} has no nickname here", user.name)
+    };
+
+    ctx.respond()
+        .content(content)
+        .ephemeral()
+        .await?;
+
+    Ok(())
+}
@@ -90,7 +90,7 @@ async fn main() {
 async fn after_hook(
     _: &SlashContext<()>,
     command_name: &str,
-    result: Option<Result<ElapsedTime, MyError>>
+    result: Option<&Result<ElapsedTime, MyError>>
 ) {
     // We don't have a custom error handler, so result will be always `Some`
     let result = result.unwrap();
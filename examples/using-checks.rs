@@ -7,7 +7,8 @@ use twilight_model::gateway::event::Event;
 use twilight_model::gateway::Intents;
 use twilight_model::http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType};
 use twilight_model::id::Id;
-use vesper::framework::DefaultError;
+use vesper::command::{ExecutionResult, ExecutionState};
+use vesper::framework::{DefaultError, ProcessResult};
 use vesper::prelude::*;
 
 #[tokio::main]
@@ -28,6 +29,7 @@ async fn main() {
 
     let framework = Framework::builder(http_client, Id::new(application_id), ())
         .command(hello)
+        .command(roll)
         .build();
 
     while let Some((_, event)) = stream.next().await {
@@ -44,7 +46,13 @@ async fn main() {
                     framework.register_global_commands().await.unwrap();
                 },
                 Event::InteractionCreate(interaction) => {
-                    framework.process(interaction.0).await;
+                    let result = framework.process(interaction.0).await;
+
+                    if let ProcessResult::CommandExecuted(ExecutionResult {
+                        state: ExecutionState::OnCooldown(remaining), ..
+                    }) = result {
+                        println!("Command is on cooldown for {} more seconds", remaining.as_secs());
+                    }
                 },
                 _ => ()
             }
@@ -85,3 +93,22 @@ async fn hello(ctx: &SlashContext<()>) -> DefaultCommandResult {
 
     Ok(())
 }
+
+#[command]
+#[description = "Rolls a dice"]
+// Once a user rolls a dice, they won't be able to roll again for 10 seconds. Other buckets
+// such as "guild", "channel" or "global" are available too.
+#[cooldown(seconds = 10, bucket = "user")]
+async fn roll(
+    ctx: &mut SlashContext<()>,
+    // When the user doesn't provide the amount of sides, a six sided dice is used.
+    #[description = "The amount of sides of the dice"] #[default = 6] sides: u64
+) -> DefaultCommandResult {
+    let roll = ctx.interaction.id.get() % sides.max(2) + 1;
+
+    ctx.respond()
+        .content(format!("You rolled a {roll}"))
+        .await?;
+
+    Ok(())
+}
@@ -49,6 +49,8 @@ async fn main() {
                         .description("Generates random characters")
                         .command(random_char)
                 })
+                // Subcommands can also be registered alongside subcommand groups.
+                .command(coin)
         })
         .build();
 
@@ -143,3 +145,15 @@ async fn random_char(ctx: &SlashContext<()>) -> DefaultCommandResult {
 
     Ok(())
 }
+
+#[command]
+#[description = "Flips a coin"]
+async fn coin(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    let side = if rand::random::<bool>() { "heads" } else { "tails" };
+
+    ctx.respond()
+        .content(format!("The coin landed on {side}"))
+        .await?;
+
+    Ok(())
+}
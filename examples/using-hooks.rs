@@ -65,7 +65,7 @@ async fn before_hook(_ctx: &SlashContext<()>, command_name: &str) -> bool {
 // The result field will be some only if the command returned no errors or if the command has
 // no custom error handler set.
 #[after]
-async fn after_hook(_ctx: &SlashContext<()>, command_name: &str, result: Option<DefaultCommandResult>) {
+async fn after_hook(_ctx: &SlashContext<()>, command_name: &str, result: Option<&DefaultCommandResult>) {
     println!("{command_name} finished, returned value: {result:?}");
 }
 
@@ -100,7 +100,7 @@ async fn handle_error(_ctx: &SlashContext<()>, result: DefaultError) {
 // will be `None`, as the error will be consumed at the custom error handler.
 #[error_handler(handle_error)]
 async fn raises_error(ctx: &SlashContext<()>) -> DefaultCommandResult {
-    ctx.defer(false).await?;
+    ctx.defer().await?;
     if !ctx.interaction.is_guild() {
         ctx.interaction_client.update_response(&ctx.interaction.token)
             .content(Some("This command can only be used in guilds")).unwrap()
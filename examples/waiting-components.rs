@@ -0,0 +1,108 @@
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use futures_util::StreamExt;
+use twilight_gateway::{stream::{self, ShardEventStream}, Config};
+use twilight_http::Client;
+use twilight_model::application::interaction::InteractionData;
+use twilight_model::channel::message::component::ButtonStyle;
+use twilight_model::gateway::event::Event;
+use twilight_model::gateway::Intents;
+use twilight_model::http::interaction::{InteractionResponse, InteractionResponseType};
+use twilight_model::id::Id;
+use vesper::components::{ActionRowBuilder, ButtonBuilder};
+use vesper::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    let token = env::var("DISCORD_TOKEN").unwrap();
+    let application_id = env::var("DISCORD_APPLICATION_ID").unwrap().parse::<u64>().unwrap();
+
+    let http_client = Arc::new(Client::new(token.clone()));
+
+    let config = Config::new(token.clone(), Intents::empty());
+    let mut shards = stream::create_recommended(
+        &http_client,
+        config,
+        |_, builder| builder.build()
+    ).await.unwrap().collect::<Vec<_>>();
+
+    let mut stream = ShardEventStream::new(shards.iter_mut());
+
+    // Commands waiting for components must not block the event loop, so the framework is shared
+    // across tasks.
+    let framework = Arc::new(Framework::builder(http_client, Id::new(application_id), ())
+        .command(confirm)
+        .build());
+
+    while let Some((_, event)) = stream.next().await {
+        match event {
+            Err(error) => {
+                if error.is_fatal() {
+                    eprintln!("Gateway connection fatally closed, error: {error:?}");
+                    break;
+                }
+            },
+            Ok(event) => match event {
+                Event::Ready(_) => {
+                    // We have to register the commands for them to show in discord.
+                    framework.register_global_commands().await.unwrap();
+                },
+                Event::InteractionCreate(interaction) => {
+                    let framework_clone = Arc::clone(&framework);
+                    tokio::spawn(async move {
+                        framework_clone.process(interaction.0).await;
+                    });
+                },
+                _ => ()
+            }
+        }
+    }
+}
+
+#[command]
+#[description = "Asks for confirmation using a button"]
+async fn confirm(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    let custom_id = ctx.interaction.id.to_string();
+
+    ctx.respond()
+        .content("Are you sure?")
+        .action_row(ActionRowBuilder::new()
+            .button(ButtonBuilder::new(ButtonStyle::Success, custom_id.clone()).label("Confirm")))
+        .await?;
+
+    let author = ctx.interaction.author_id();
+
+    // Only the user who invoked the command can press the button, presses from other users
+    // are ignored by this waiter.
+    let waiter = ctx.wait_component(move |interaction| {
+        let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+            return false;
+        };
+
+        data.custom_id == custom_id && interaction.author_id() == author
+    });
+
+    let content = match waiter.timeout(Duration::from_secs(30)).await? {
+        Some(interaction) => {
+            ctx.interaction_client.create_response(
+                interaction.id,
+                &interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::DeferredUpdateMessage,
+                    data: None
+                }
+            ).await?;
+
+            "Confirmed!"
+        },
+        None => "You took too long to confirm"
+    };
+
+    ctx.update_response()
+        .content(Some(content))?
+        .components(Some(&[]))?
+        .await?;
+
+    Ok(())
+}
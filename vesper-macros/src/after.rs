@@ -1,5 +1,6 @@
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{parse2, spanned::Spanned, Error, ItemFn, Result, Path};
+use proc_macro2::Span;
+use syn::{parse2, spanned::Spanned, Error, GenericArgument, ItemFn, Lifetime, PathArguments, Result, Type};
 use crate::util;
 
 /// The implementation of after macro, this macro takes the given input, which must be another
@@ -35,9 +36,10 @@ pub fn after(input: TokenStream2) -> Result<TokenStream2> {
     */
     util::check_return_type(&sig.output, quote::quote!(()))?;
 
-    let result_type = util::get_path(&util::get_pat(sig.inputs.iter().nth(2).unwrap())?.ty, false)?;
+    // The result is given as `Option<&Result<T, E>>`, get the `Result<T, E>` and bind the
+    // reference to the lifetime of the returned future.
+    let result_type = result_type(&mut util::get_pat_mut(sig.inputs.iter_mut().nth(2).unwrap())?.ty)?;
     let returnable = util::get_returnable_trait();
-    let optional = parse2::<Path>(quote::quote!(::vesper::extract::Optional))?;
 
     let ty = util::get_context_type(&sig, true)?;
     // Get the hook macro so we can fit the function into a normal fn pointer
@@ -48,8 +50,8 @@ pub fn after(input: TokenStream2) -> Result<TokenStream2> {
         pub fn #ident()
         -> #path<
             #ty,
-            <<#result_type as #optional>::Inner as #returnable>::Ok,
-            <<#result_type as #optional>::Inner as #returnable>::Err
+            <#result_type as #returnable>::Ok,
+            <#result_type as #returnable>::Err
         > {
             #path(#fn_ident)
         }
@@ -59,3 +61,24 @@ pub fn after(input: TokenStream2) -> Result<TokenStream2> {
         #vis #sig #block
     })
 }
+
+/// Gets the `Result<T, E>` type of an `Option<&Result<T, E>>`, assigning the lifetime of the
+/// hook future to the reference.
+fn result_type(ty: &mut Type) -> Result<Type> {
+    let span = ty.span();
+    let error = || Error::new(span, "Expected an `Option<&Result<T, E>>`");
+    let path = util::get_path_mut(ty)?;
+
+    let segment = path.segments.last_mut().ok_or_else(error)?;
+    let PathArguments::AngleBracketed(arguments) = &mut segment.arguments else {
+        return Err(error());
+    };
+
+    match arguments.args.first_mut() {
+        Some(GenericArgument::Type(Type::Reference(reference))) => {
+            reference.lifetime = Some(Lifetime::new("'future", Span::call_site()));
+            Ok((*reference.elem).clone())
+        },
+        _ => Err(error())
+    }
+}
@@ -33,9 +33,22 @@ pub fn check(input: TokenStream2) -> Result<TokenStream2> {
     let hook = util::get_hook_macro();
     let path = quote::quote!(::vesper::hook::CheckHook);
 
+    let result = quote::quote!(::vesper::hook::CheckResult);
+    let error = quote::quote!(<#return_type as #returnable>::Err);
+
+    // The check may return either a `bool` or a `CheckResult`, so it is wrapped into a function
+    // converting its output into a `CheckResult`.
     Ok(quote::quote! {
-        pub fn #ident() -> #path<#ty, <#return_type as #returnable>::Err> {
-            #path(#fn_ident)
+        pub fn #ident() -> #path<#ty, #error> {
+            fn __check<'cx, 'data>(
+                ctx: &'cx mut ::vesper::prelude::SlashContext<'data, #ty>
+            ) -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = ::std::result::Result<#result, #error>> + 'cx + Send>> {
+                Box::pin(async move {
+                    ::std::result::Result::map(#fn_ident(ctx).await, ::std::convert::Into::into)
+                })
+            }
+
+            #path(__check)
         }
 
         #[#hook]
@@ -0,0 +1,256 @@
+use darling::FromAttributes;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use syn::{spanned::Spanned, DeriveInput, Error, Lit, LitStr, Result};
+use crate::extractors::Map;
+
+#[derive(FromAttributes)]
+#[darling(attributes(choice))]
+struct VariantAttributes {
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    value: Option<Lit>,
+    #[darling(default)]
+    name_localizations: Option<Map<LitStr, LitStr>>,
+    /// Whether the variant captures the values not matching any other choice.
+    #[darling(default)]
+    other: bool
+}
+
+/// The value a choice variant takes, all the variants of a choice must have the same value type.
+enum ChoiceValue {
+    String(String),
+    Integer(i64),
+    Number(f64)
+}
+
+impl ChoiceValue {
+    /// Returns the kind of this value, used to check all the variants have the same value type.
+    fn kind(&self) -> u8 {
+        match self {
+            Self::String(_) => 0,
+            Self::Integer(_) => 1,
+            Self::Number(_) => 2
+        }
+    }
+}
+
+struct Variant {
+    ident: Ident,
+    name: String,
+    value: ChoiceValue,
+    localizations: Option<Map<LitStr, LitStr>>
+}
+
+impl Variant {
+    fn parse_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.ident;
+        let pattern = match &self.value {
+            ChoiceValue::String(s) => quote::quote!(#s),
+            ChoiceValue::Integer(i) => quote::quote!(#i),
+            // Floating point literals can't be used as patterns, so a guard is used instead.
+            ChoiceValue::Number(n) => quote::quote!(value if value == #n)
+        };
+
+        tokens.extend(quote::quote! {
+            #pattern => Ok(Self::#ident),
+        })
+    }
+
+    fn choice_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.name;
+        let value = match &self.value {
+            ChoiceValue::String(s) => quote::quote!(CommandOptionChoiceValue::String(#s.to_string())),
+            ChoiceValue::Integer(i) => quote::quote!(CommandOptionChoiceValue::Integer(#i)),
+            ChoiceValue::Number(n) => quote::quote!(CommandOptionChoiceValue::Number(#n))
+        };
+        let localizations = match &self.localizations {
+            Some(map) => {
+                let pairs = map.pairs();
+                quote::quote! {
+                    Some(vec![#(#pairs),*]
+                        .into_iter()
+                        .map(|(k, v): (&str, &str)| (k.to_string(), v.to_string()))
+                        .collect())
+                }
+            },
+            None => quote::quote!(None)
+        };
+
+        tokens.extend(quote::quote! {
+            CommandOptionChoice {
+                name: #name.to_string(),
+                name_localizations: #localizations,
+                value: #value
+            },
+        })
+    }
+}
+
+pub fn choice(input: TokenStream2) -> Result<TokenStream2> {
+    let derive = syn::parse2::<DeriveInput>(input)?;
+    let enumeration = match derive.data {
+        syn::Data::Enum(e) => e,
+        _ => {
+            return Err(Error::new(
+                derive.ident.span(),
+                "This derive is only available for enums",
+            ))
+        }
+    };
+
+    let mut variants = Vec::new();
+    let mut fallback = None;
+
+    for variant in enumeration.variants {
+        let attributes = VariantAttributes::from_attributes(variant.attrs.as_slice())?;
+
+        if attributes.other {
+            if fallback.is_some() {
+                return Err(Error::new(variant.span(), "Only one variant can be marked as `other`"));
+            }
+
+            if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+                return Err(Error::new(
+                    variant.span(),
+                    "The `other` variant must contain a single String, like `Other(String)`",
+                ));
+            }
+
+            fallback = Some(variant.ident);
+            continue;
+        }
+
+        if !matches!(&variant.fields, syn::Fields::Unit) {
+            return Err(Error::new(
+                variant.span(),
+                "Choice parameter cannot have inner values",
+            ));
+        }
+
+        let name = attributes.name.unwrap_or_else(|| variant.ident.to_string());
+
+        let value = match attributes.value {
+            None => ChoiceValue::String(name.clone()),
+            Some(Lit::Str(s)) => ChoiceValue::String(s.value()),
+            Some(Lit::Int(i)) => ChoiceValue::Integer(i.base10_parse()?),
+            Some(Lit::Float(f)) => ChoiceValue::Number(f.base10_parse()?),
+            Some(other) => {
+                return Err(Error::new(
+                    other.span(),
+                    "Choice value must be a string, an integer or a number",
+                ))
+            }
+        };
+
+        variants.push(Variant {
+            ident: variant.ident,
+            name,
+            value,
+            localizations: attributes.name_localizations
+        });
+    }
+
+    let kind = variants.first().map(|v| v.value.kind()).unwrap_or_default();
+
+    if variants.iter().any(|v| v.value.kind() != kind) {
+        return Err(Error::new(
+            derive.ident.span(),
+            "All choice values must be of the same type",
+        ));
+    }
+
+    if fallback.is_some() && kind != 0 {
+        return Err(Error::new(
+            derive.ident.span(),
+            "The `other` variant can only be used with string choices",
+        ));
+    }
+
+    let mut parse_stream = TokenStream2::new();
+    let mut choice_stream = TokenStream2::new();
+    for variant in &variants {
+        variant.parse_tokens(&mut parse_stream);
+        variant.choice_tokens(&mut choice_stream);
+    }
+
+    let (value_type, kind, matched) = match variants.first().map(|v| &v.value) {
+        Some(ChoiceValue::Integer(_)) => {
+            (quote::quote!(i64), quote::quote!(CommandOptionType::Integer), quote::quote!(value))
+        },
+        Some(ChoiceValue::Number(_)) => {
+            (quote::quote!(f64), quote::quote!(CommandOptionType::Number), quote::quote!(value))
+        },
+        _ => (quote::quote!(String), quote::quote!(CommandOptionType::String), quote::quote!(value.as_str()))
+    };
+
+    let enum_name = &derive.ident;
+
+    let unmatched = match &fallback {
+        Some(ident) => quote::quote!(_ => Ok(Self::#ident(value))),
+        None => quote::quote! {
+            _ => Err(ParseError::OutOfRange {
+                    argument_name: String::new(),
+                    argument_type: String::from(stringify!(#enum_name)),
+                    error: String::from("The value is not one of the choices")
+                }
+            )
+        }
+    };
+
+    // Discord only accepts the registered choices, so they can't be registered when arbitrary
+    // values are allowed, these can be suggested using autocomplete instead.
+    let registered_choices = match &fallback {
+        Some(_) => quote::quote!(None),
+        None => quote::quote!(Some(Self::choices()))
+    };
+
+    Ok(quote::quote! {
+        const _: () = {
+            use ::vesper::{
+                builder::WrappedClient,
+                prelude::async_trait,
+                parse::{Parse, ParseError},
+                twilight_exports::{
+                    CommandInteractionDataResolved,
+                    CommandOptionChoice,
+                    CommandOptionChoiceValue,
+                    CommandOptionType,
+                    CommandOptionValue,
+                },
+            };
+
+            #[automatically_derived]
+            impl #enum_name {
+                /// Returns the choices used to register this enum as a command argument.
+                pub fn choices() -> Vec<CommandOptionChoice> {
+                    vec![#choice_stream]
+                }
+            }
+
+            #[automatically_derived]
+            #[async_trait]
+            impl<T: Send + Sync + 'static> Parse<T> for #enum_name {
+                async fn parse(
+                    http_client: &WrappedClient,
+                    data: &T,
+                    value: Option<&CommandOptionValue>,
+                    resolved: Option<&mut CommandInteractionDataResolved>
+                ) -> Result<Self, ParseError>
+                {
+                    let value = <#value_type as Parse<T>>::parse(http_client, data, value, resolved).await?;
+                    match #matched {
+                        #parse_stream
+                        #unmatched
+                    }
+                }
+                fn kind() -> CommandOptionType {
+                    #kind
+                }
+                fn choices() -> Option<Vec<CommandOptionChoice>> {
+                    #registered_choices
+                }
+            }
+        };
+    })
+}
@@ -1,4 +1,4 @@
-use crate::extractors::{Either, FixedList, FunctionPath, Map};
+use crate::extractors::{Either, Expression, FixedList, FunctionPath, Map};
 use crate::optional::Optional;
 use crate::util;
 use darling::FromMeta;
@@ -37,6 +37,20 @@ pub struct ArgumentAttributes {
     #[darling(rename = "rename")]
     pub renaming: Option<Either<String, FixedList<1, String>>>,
     pub autocomplete: Optional<Either<FunctionPath, FixedList<1, FunctionPath>>>,
+    /// The value used when the argument is not provided, parsed with `#[default = expr]`. Arguments
+    /// with a default value are registered as not required.
+    #[darling(default)]
+    pub default: Option<Expression>,
+    /// Whether the argument is required, overriding the value inferred from its type, parsed
+    /// with `#[required = bool]`.
+    #[darling(default)]
+    pub required: Option<bool>,
+    /// The minimum length of a string argument, parsed with `#[min_length = n]`.
+    #[darling(default)]
+    pub min_length: Option<u16>,
+    /// The maximum length of a string argument, parsed with `#[max_length = n]`.
+    #[darling(default)]
+    pub max_length: Option<u16>,
     #[darling(default)]
     pub skip: bool
 }
@@ -84,6 +98,13 @@ impl Argument {
             chat_command
         };
 
+        if this.attributes.as_ref().map(|a| a.required.is_some() && a.default.is_some()).unwrap_or(false) {
+            return Err(Error::new(
+                arg.span(),
+                "`required` can't be used along with `default`"
+            ).into());
+        }
+
         if chat_command 
             && !this.attributes.as_ref().map(|a| a.skip).unwrap_or(false)
             && this.attributes.as_ref().unwrap()
@@ -97,6 +118,53 @@ impl Argument {
 
         Ok(this)
     }
+
+    /// Returns the default value of this argument, if it has one.
+    pub fn default_value(&self) -> Option<&Expression> {
+        self.attributes.as_ref().and_then(|attributes| attributes.default.as_ref())
+    }
+
+    /// Returns the name this argument is registered with.
+    pub fn name(&self) -> String {
+        match self.attributes.as_ref().and_then(|attributes| attributes.renaming.as_ref()) {
+            Some(rename) => rename.inner().clone(),
+            None => self.ident.to_string()
+        }
+    }
+
+    /// Returns whether the argument is required, if it was explicitly set.
+    pub fn required(&self) -> Option<bool> {
+        self.attributes.as_ref().and_then(|attributes| attributes.required)
+    }
+
+    /// Returns the code checking the length of this argument, if it has length constraints.
+    pub fn length_check(&self, name: &str, ty: &TokenStream) -> Option<TokenStream> {
+        let attributes = self.attributes.as_ref()?;
+        if attributes.min_length.is_none() && attributes.max_length.is_none() {
+            return None;
+        }
+
+        let min = optional_tokens(attributes.min_length);
+        let max = optional_tokens(attributes.max_length);
+        Some(quote::quote!(__options.check_length::<#ty>(#name, #min, #max)?;))
+    }
+
+    /// Returns the type used to parse and register this argument, arguments with a default value
+    /// are parsed as an `Option` of its type.
+    pub fn parsed_type(&self) -> TokenStream {
+        let ty = &self.ty;
+        match self.default_value() {
+            Some(_) => quote::quote!(Option<#ty>),
+            None => quote::quote!(#ty)
+        }
+    }
+}
+
+fn optional_tokens(value: Option<u16>) -> TokenStream {
+    match value {
+        Some(value) => quote::quote!(Some(#value)),
+        None => quote::quote!(None)
+    }
 }
 
 impl ToTokens for Argument {
@@ -107,13 +175,10 @@ impl ToTokens for Argument {
         let attributes = self.attributes.as_ref().unwrap();
 
         let des = attributes.description.as_ref().unwrap().inner();
-        let ty = &self.ty;
+        let ty = self.parsed_type();
         let argument_path = quote::quote!(::vesper::argument::CommandArgument);
 
-        let name = match &attributes.renaming {
-            Some(rename) => rename.inner().clone(),
-            None => self.ident.to_string(),
-        };
+        let name = self.name();
 
         let add_localized_names = attributes.localized_names.as_ref().map(|map| {
             let localized_names = map.pairs();
@@ -135,6 +200,10 @@ impl ToTokens for Argument {
             quote::quote!(.localized_descriptions_fn(#fun))
         });
 
+        let required = attributes.required.map(|required| quote::quote!(.required(#required)));
+        let min_length = attributes.min_length.map(|length| quote::quote!(.min_length(#length)));
+        let max_length = attributes.max_length.map(|length| quote::quote!(.max_length(#length)));
+
         let autocomplete = attributes.autocomplete.as_ref().map(|either| {
             let inner = either.inner();
             quote::quote!(#inner())
@@ -150,6 +219,9 @@ impl ToTokens for Argument {
             #add_localized_names_fn
             #add_localized_descriptions
             #add_localized_descriptions_fn
+            #required
+            #min_length
+            #max_length
             )   
         });
     }
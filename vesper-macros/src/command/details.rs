@@ -1,5 +1,5 @@
 use darling::{FromMeta, export::NestedMeta};
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{Token, Meta, parse2, Error, LitStr};
@@ -19,7 +19,8 @@ pub struct CommandDetails {
     pub localized_names: Option<Map<LitStr, LitStr>>,
     #[darling(default)]
     pub localized_names_fn: Option<Either<FunctionOrClosure, FixedList<1, FunctionOrClosure>>>,
-    /// The description of this command
+    /// The description of this command, only required by chat commands.
+    #[darling(default)]
     pub description: Either<String, FixedList<1, String>>,
     #[darling(default)]
     pub localized_descriptions: Option<Map<LitStr, LitStr>>,
@@ -27,6 +28,9 @@ pub struct CommandDetails {
     pub localized_descriptions_fn: Option<Either<FunctionOrClosure, FixedList<1, FunctionOrClosure>>>,
     #[darling(default)]
     pub required_permissions: Option<List<Ident>>,
+    /// An alias of `required_permissions`, following the name discord uses.
+    #[darling(default)]
+    pub default_member_permissions: Option<List<Ident>>,
     #[darling(default)]
     pub checks: Either<List<FunctionPath>, Punctuated<FunctionPath, Token![,]>>,
     #[darling(default)]
@@ -34,7 +38,109 @@ pub struct CommandDetails {
     #[darling(default)]
     pub nsfw: bool,
     #[darling(default)]
-    pub only_guilds: bool
+    pub only_guilds: bool,
+    /// Whether the responses of the command are ephemeral by default.
+    #[darling(default)]
+    pub ephemeral: bool,
+    /// Whether the command can be used in direct messages, the opposite of `only_guilds`.
+    #[darling(default)]
+    pub dm_permission: Option<Either<bool, FixedList<1, bool>>>,
+    #[darling(default)]
+    pub cooldown: Option<CooldownOptions>,
+    /// The locales allowed to use the command, specified as `#[restrict_locales("en-US", "en-GB")]`.
+    #[darling(default)]
+    pub restrict_locales: Option<List<LitStr>>,
+    #[darling(default)]
+    pub auto_defer: Option<AutoDeferOptions>,
+    /// The installation contexts of the command, specified as
+    /// `#[integration_types(guild_install, user_install)]`.
+    #[darling(default)]
+    pub integration_types: Option<List<Ident>>,
+    /// The contexts the command can be used in, specified as
+    /// `#[contexts(guild, bot_dm, private_channel)]`.
+    #[darling(default)]
+    pub contexts: Option<List<Ident>>,
+    /// Optional arguments where at least one must be provided, specified as
+    /// `#[require_one_of("name", "color")]`.
+    #[darling(default)]
+    pub require_one_of: Option<List<LitStr>>
+}
+
+/// The integration types accepted by `#[integration_types]`, with the variant they map to.
+const INTEGRATION_TYPES: &[(&str, &str)] = &[
+    ("guild_install", "GuildInstall"),
+    ("user_install", "UserInstall")
+];
+
+/// The contexts accepted by `#[contexts]`, with the variant they map to.
+const CONTEXTS: &[(&str, &str)] = &[
+    ("guild", "Guild"),
+    ("bot_dm", "BotDm"),
+    ("private_channel", "PrivateChannel")
+];
+
+/// Replaces the given names by the variants they map to, failing if any of them is not valid.
+fn map_variants(list: &mut List<Ident>, variants: &[(&str, &str)], attribute: &str) -> Result<()> {
+    for item in list.iter_mut() {
+        let name = item.to_token_stream().to_string();
+        let Some((_, variant)) = variants.iter().find(|(key, _)| *key == name) else {
+            let expected = variants.iter().map(|(key, _)| *key).collect::<Vec<_>>().join(", ");
+            return Err(Error::new(
+                item.span(),
+                format!("Invalid value `{}` for `{}`, expected one of: {}", name, attribute, expected)
+            ));
+        };
+
+        *item = <Ident as FromMeta>::from_string(variant)?;
+    }
+
+    Ok(())
+}
+
+#[derive(FromMeta)]
+/// The time after which the command is deferred, specified as `#[auto_defer(millis = 2000)]`.
+pub struct AutoDeferOptions {
+    pub millis: u64
+}
+
+#[derive(FromMeta)]
+/// The options of a command cooldown, specified as `#[cooldown(seconds = 5, bucket = "user")]`.
+pub struct CooldownOptions {
+    pub seconds: u64,
+    #[darling(default)]
+    pub bucket: CooldownBucket
+}
+
+#[derive(Default, FromMeta)]
+pub enum CooldownBucket {
+    #[default]
+    #[darling(rename = "user")]
+    User,
+    #[darling(rename = "guild")]
+    Guild,
+    #[darling(rename = "channel")]
+    Channel,
+    #[darling(rename = "global")]
+    Global
+}
+
+impl ToTokens for CooldownOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let seconds = self.seconds;
+        let bucket = match self.bucket {
+            CooldownBucket::User => quote::quote!(User),
+            CooldownBucket::Guild => quote::quote!(Guild),
+            CooldownBucket::Channel => quote::quote!(Channel),
+            CooldownBucket::Global => quote::quote!(Global)
+        };
+
+        tokens.extend(quote::quote! {
+            ::vesper::cooldown::Cooldown::new(
+                ::std::time::Duration::from_secs(#seconds),
+                ::vesper::cooldown::CooldownBucket::#bucket
+            )
+        });
+    }
 }
 
 impl CommandDetails {
@@ -47,6 +153,39 @@ impl CommandDetails {
 
         let mut this = Self::from_list(meta.as_slice())?;
 
+        if this.default_member_permissions.is_some() {
+            if this.required_permissions.is_some() {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "Only one of `required_permissions` or `default_member_permissions` can be used"
+                ));
+            }
+
+            this.required_permissions = this.default_member_permissions.take();
+        }
+
+        if let Some(dm_permission) = this.dm_permission.take() {
+            if this.only_guilds {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "Only one of `only_guilds` or `dm_permission` can be used"
+                ));
+            }
+
+            this.only_guilds = !*dm_permission.inner();
+        }
+
+        if let Some(integration_types) = &mut this.integration_types {
+            map_variants(integration_types, INTEGRATION_TYPES, "integration_types")?;
+        }
+
+        if let Some(contexts) = &mut this.contexts {
+            map_variants(contexts, CONTEXTS, "contexts")?;
+        }
+
+        // The nsfw and ephemeral flags can be specified both inside `#[command]` and as attributes.
+        this.nsfw |= input_options.nsfw;
+        this.ephemeral |= input_options.ephemeral;
         this.input_options = input_options;
         Ok(this)
     }
@@ -113,12 +252,47 @@ impl ToTokens for CommandDetails {
             tokens.extend(quote::quote!(.error_handler(#error_handler())));
         }
 
+        if let Some(cooldown) = &self.cooldown {
+            tokens.extend(quote::quote!(.cooldown(#cooldown)));
+        }
+
+        if let Some(auto_defer) = &self.auto_defer {
+            let millis = auto_defer.millis;
+            tokens.extend(quote::quote!(.auto_defer(::std::time::Duration::from_millis(#millis))));
+        }
+
+        if let Some(integration_types) = &self.integration_types {
+            let integration_types = integration_types.iter();
+            tokens.extend(quote::quote!(
+                .integration_types([#(::vesper::integration::IntegrationType::#integration_types),*])
+            ));
+        }
+
+        if let Some(contexts) = &self.contexts {
+            let contexts = contexts.iter();
+            tokens.extend(quote::quote!(
+                .contexts([#(::vesper::integration::InteractionContext::#contexts),*])
+            ));
+        }
+
+        if let Some(locales) = &self.restrict_locales {
+            let locales = locales.iter();
+            tokens.extend(quote::quote!(.restrict_locales([#(#locales),*])));
+        }
+
+        if let Some(arguments) = &self.require_one_of {
+            let arguments = arguments.iter();
+            tokens.extend(quote::quote!(.require_one_of([#(#arguments),*])));
+        }
+
         let nsfw = self.nsfw;
         let only_guilds = self.only_guilds;
+        let ephemeral = self.ephemeral;
 
         tokens.extend(quote::quote!(
             .nsfw(#nsfw)
             .only_guilds(#only_guilds)
+            .ephemeral(#ephemeral)
         ));
     }
 }
@@ -132,11 +306,22 @@ pub struct InputOptions {
     #[darling(default)]
     pub user: bool,
     #[darling(default)]
-    pub name: String
+    pub name: String,
+    #[darling(default)]
+    pub nsfw: bool,
+    #[darling(default)]
+    pub ephemeral: bool
 }
 
 impl InputOptions {
     pub fn new(stream: TokenStream2, ident: &syn::Ident) -> Result<Self> {
+        let span = stream.span();
+        let this = Self::parse(stream, ident)?;
+        this.validate_name(span)?;
+        Ok(this)
+    }
+
+    fn parse(stream: TokenStream2, ident: &syn::Ident) -> Result<Self> {
         let stream_empty = stream.is_empty();
         let stream_clone = stream.clone();
         let span = stream.span();
@@ -175,6 +360,58 @@ impl InputOptions {
 
         Ok(this)
     }
+
+    /// Parses the input options of a message command, which can only specify the command name.
+    pub fn message(stream: TokenStream2, ident: &syn::Ident) -> Result<Self> {
+        Self::context_menu(stream, ident, false)
+    }
+
+    /// Parses the input options of a user command, which can only specify the command name.
+    pub fn user(stream: TokenStream2, ident: &syn::Ident) -> Result<Self> {
+        Self::context_menu(stream, ident, true)
+    }
+
+    fn context_menu(stream: TokenStream2, ident: &syn::Ident, user: bool) -> Result<Self> {
+        let span = stream.span();
+        let mut this = Self::parse(stream, ident)?;
+
+        if this.message || this.user {
+            return Err(Error::new(span, "The command type can't be specified in context menu commands"));
+        }
+
+        this.chat = false;
+        this.message = !user;
+        this.user = user;
+        this.validate_name(span)?;
+
+        Ok(this)
+    }
+
+    /// Validates the name of the command against discord naming rules, chat command names must be
+    /// lowercase and can only contain letters, numbers, `-` and `_`, while context menu commands
+    /// can use any character. In both cases, names must have between 1 and 32 characters.
+    fn validate_name(&self, span: Span) -> Result<()> {
+        let length = self.name.chars().count();
+        if !(1..=32).contains(&length) {
+            return Err(Error::new(
+                span,
+                format!("Command name `{}` must have between 1 and 32 characters, but has {}", self.name, length)
+            ));
+        }
+
+        let valid = |c: char| (c.is_alphanumeric() || c == '-' || c == '_') && !c.is_uppercase();
+        if self.chat && !self.name.chars().all(valid) {
+            return Err(Error::new(
+                span,
+                format!(
+                    "Command name `{}` is not valid, chat command names must be lowercase and can only contain letters, numbers, `-` and `_`",
+                    self.name
+                )
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl ToTokens for InputOptions {
@@ -13,7 +13,32 @@ use self::details::InputOptions;
 /// types and descriptions.
 pub fn command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
     let fun = parse2::<ItemFn>(input)?;
+    let input_options = InputOptions::new(macro_attrs, &fun.sig.ident)?;
 
+    expand(input_options, fun)
+}
+
+/// The implementation of the message command macro, this works the same way as the command macro,
+/// but the command is registered as a message command and the arguments of the function receive
+/// the targeted message.
+pub fn message_command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
+    let fun = parse2::<ItemFn>(input)?;
+    let input_options = InputOptions::message(macro_attrs, &fun.sig.ident)?;
+
+    expand(input_options, fun)
+}
+
+/// The implementation of the user command macro, this works the same way as the message command
+/// macro, but the command is registered as a user command, so the function arguments receive the
+/// targeted user and member.
+pub fn user_command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
+    let fun = parse2::<ItemFn>(input)?;
+    let input_options = InputOptions::user(macro_attrs, &fun.sig.ident)?;
+
+    expand(input_options, fun)
+}
+
+fn expand(input_options: InputOptions, fun: ItemFn) -> Result<TokenStream2> {
     let ItemFn {
         mut attrs,
         vis,
@@ -29,8 +54,6 @@ pub fn command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenSt
         ));
     }
 
-    let input_options = InputOptions::new(macro_attrs, &sig.ident)?;
-
     // The name of the function
     let ident = sig.ident.clone();
     // The name the function will have after macro execution
@@ -53,6 +76,16 @@ pub fn command(macro_attrs: TokenStream2, input: TokenStream2) -> Result<TokenSt
     )?;
     let opts = CommandDetails::parse(input_options, &mut attrs)?;
 
+    if opts.input_options.chat && opts.description.inner().is_empty() {
+        return Err(Error::new(ident.span(), "Missing `description`"));
+    }
+
+    if let Some(required) = &opts.require_one_of {
+        if let Some(unknown) = required.iter().find(|name| !args.iter().any(|arg| arg.name() == name.value())) {
+            return Err(Error::new(unknown.span(), format!("Unknown argument `{}`", unknown.value())));
+        }
+    }
+
     Ok(quote::quote! {
         pub fn #ident() -> #command_path<#context_type, <#output as #returnable>::Ok, <#output as #returnable>::Err> {
             #command_path::new(#fn_ident)
@@ -83,9 +116,16 @@ pub fn parse_arguments(
 
     arguments.reverse();
 
-    let (names, types, renames) = (
+    if !chat_command {
+        return parse_target(arguments, block, ctx_ident);
+    }
+
+    let (names, types, defaults, renames) = (
         arguments.iter().map(|s| &s.ident).collect::<Vec<_>>(),
-        arguments.iter().map(|s| &s.ty).collect::<Vec<_>>(),
+        arguments.iter().map(|s| s.parsed_type()).collect::<Vec<_>>(),
+        arguments.iter()
+            .map(|s| s.default_value().map(|default| quote::quote!(.unwrap_or_else(|| #default))))
+            .collect::<Vec<_>>(),
         arguments
             .iter()
             .map(|s| {
@@ -98,6 +138,21 @@ pub fn parse_arguments(
             .collect::<Vec<_>>(),
     );
 
+    let parse_calls = arguments.iter()
+        .zip(&types)
+        .zip(&renames)
+        .map(|((argument, ty), name)| match argument.required() {
+            Some(required) => quote::quote!(__options.named_parse_as::<#ty>(#name, #required)),
+            None => quote::quote!(__options.named_parse::<#ty>(#name))
+        })
+        .collect::<Vec<_>>();
+
+    let length_checks = arguments.iter()
+        .zip(&types)
+        .zip(&renames)
+        .map(|((argument, ty), name)| argument.length_check(name, ty))
+        .collect::<Vec<_>>();
+
     if !names.is_empty() {
         // The original block of the function
         let b = &block;
@@ -107,8 +162,8 @@ pub fn parse_arguments(
             let (#(#names),*) = {
                 let mut __options = ::vesper::iter::DataIterator::new(#ctx_ident);
 
-                #(let #names =
-                    __options.named_parse::<#types>(#renames).await?;)*
+                #(#length_checks
+                let #names = #parse_calls.await?#defaults;)*
 
                 if __options.len() > 0 {
                     return Err(
@@ -126,6 +181,25 @@ pub fn parse_arguments(
     Ok(arguments)
 }
 
+/// Prepares the given function to receive the target of a context menu command, every argument
+/// is retrieved from the target of the command.
+fn parse_target(arguments: Vec<Argument>, block: &mut Block, ctx_ident: Ident) -> Result<Vec<Argument>> {
+    if !arguments.is_empty() {
+        let (names, types) = (
+            arguments.iter().map(|s| &s.ident).collect::<Vec<_>>(),
+            arguments.iter().map(|s| &s.ty).collect::<Vec<_>>(),
+        );
+        let b = &block;
+
+        *block = parse2(quote::quote! {{
+            #(let #names = #ctx_ident.target::<#types>()?;)*
+
+            #b
+        }})?;
+    }
+
+    Ok(arguments)
+}
 
 /// Gets the identifier and the type of the first argument of a function, which must be an
 /// `SlashContext`
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse2, spanned::Spanned, Error, ItemFn, Result};
+use crate::util;
+
+/// The implementation of the component macro, this macro takes the given function and prepares
+/// it to be used as a component handler, wrapping it in a struct and providing a pointer to the
+/// actual function.
+pub fn component(input: TokenStream2) -> Result<TokenStream2> {
+    let fun = parse2::<ItemFn>(input)?;
+    let ItemFn {
+        attrs,
+        vis,
+        mut sig,
+        block,
+    } = fun;
+
+    if sig.inputs.len() != 2 {
+        // This hook is expected to have a `&SlashContext` and a `&str` parameter, containing
+        // the payload of the custom id.
+        return Err(Error::new(
+            sig.inputs.span(),
+            "Function parameter must only be &SlashContext and &str",
+        ));
+    }
+
+    // The name of the original function
+    let ident = sig.ident.clone();
+    // The name the function will have after this macro's execution
+    let fn_ident = quote::format_ident!("_{}", &ident);
+    sig.ident = fn_ident.clone();
+
+    let return_type = util::get_return_type(&sig)?;
+    let returnable = util::get_returnable_trait();
+
+    let ty = util::get_context_type(&sig, true)?;
+    // Get the hook macro so we can fit the function into a normal fn pointer
+    let hook = util::get_hook_macro();
+    let path = quote::quote!(::vesper::hook::ComponentHook);
+
+    Ok(quote::quote! {
+        pub fn #ident() -> #path<#ty, <#return_type as #returnable>::Ok, <#return_type as #returnable>::Err> {
+            #path(#fn_ident)
+        }
+
+        #[#hook]
+        #(#attrs)*
+        #vis #sig #block
+    })
+}
@@ -0,0 +1,23 @@
+use darling::FromMeta;
+use quote::ToTokens;
+use syn::Expr;
+
+/// An expression given as the value of an attribute, unlike [`syn::Expr`], string literals are
+/// kept as they are instead of being parsed as expressions.
+#[derive(Clone)]
+pub struct Expression(Expr);
+
+impl FromMeta for Expression {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Ok(Self(expr.clone()))
+        }
+    }
+}
+
+impl ToTokens for Expression {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod closure;
 pub mod either;
+pub mod expression;
 pub mod function_closure;
 pub mod function_path;
 pub mod ident;
@@ -9,6 +10,7 @@ pub mod tuple;
 
 pub use {
     either::*,
+    expression::*,
     function_path::*,
     ident::*,
     list::*,
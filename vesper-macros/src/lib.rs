@@ -6,13 +6,17 @@ mod after;
 mod autocomplete;
 mod before;
 mod check;
+mod choice;
 mod extractors;
 mod command;
+mod component;
 mod error_handler;
+mod execution;
 mod hook;
 mod modal;
 mod optional;
 mod parse;
+mod unknown_command;
 mod util;
 
 /// Converts an `async` function into a normal function returning a
@@ -62,12 +66,25 @@ pub fn hook(_: TokenStream, input: TokenStream) -> TokenStream {
 /// the user's input for an argument. This attribute is used the same way as the description one,
 /// but it *must* point to a function marked with the `#[autocomplete]` attribute macro.
 ///
+/// ### Default values:
+/// Arguments can have a default value using `#[default = expr]`, which is used when the argument is
+/// not provided. These arguments are registered as not required, and the expression must have the
+/// same type as the argument, like `#[default = String::from("none")] reason: String`.
+///
+/// ### Required arguments:
+/// Whether an argument is required is inferred from its type, which can be overridden using
+/// `#[required = bool]`.
+///
+/// ### Length constraints:
+/// The length of string arguments can be constrained using `#[min_length = n]` and
+/// `#[max_length = n]`, which are registered in discord and checked when parsing the argument.
+///
 /// ### Localizations:
 /// Localizations can be applied in both commands and their arguments, for that, the `#[localized_names]` and
 /// `#[localized_descriptions]` attributes can be used, these accept a comma separated list of key-value items:
 /// ```
 /// #[command]
-/// #[localized_names("en-US" = "US name", "en-GB" = "GB name", "es-ES" = "Spanish name")]
+/// #[localized_names("en-US" = "us-name", "en-GB" = "gb-name", "es-ES" = "nombre")]
 /// #[description("My description")]
 /// #[localized_descriptions("en-US" = "US description", "en-GB" = "GB description", "es-ES" = "Spanish description")]
 /// async fn my_command(context: &mut SlashContext</* Context type */>) -> DefaultCommandResult {
@@ -94,11 +111,119 @@ pub fn hook(_: TokenStream, input: TokenStream) -> TokenStream {
 /// [twilight permissions](https://docs.rs/twilight-model/latest/twilight_model/guild/struct.Permissions.html).
 /// For example, to specify that a user needs to have administrator permissions to execute a command,
 /// the attribute would be used like this `#[required_permissions(ADMINISTRATOR)]`.
+///
+/// These are registered as the default member permissions of the command, so the
+/// `#[default_member_permissions]` attribute can be used instead, matching the name discord uses.
+///
+/// ## Age-restricted commands
+///
+/// Commands can be marked as age-restricted using either `#[command(nsfw)]` or the `#[nsfw]`
+/// attribute.
+///
+/// ## Cooldowns
+///
+/// A cooldown can be set using `#[cooldown(seconds = 10, bucket = "user")]`, where the bucket is
+/// one of `user`, `guild`, `channel` or `global`, defaulting to `user`.
+///
+/// ## Ephemeral responses
+///
+/// Using `#[command(ephemeral)]` or the `#[ephemeral]` attribute makes the responses and deferred
+/// responses of the command ephemeral by default.
+///
+/// ## Deferring slow commands
+///
+/// Using `#[auto_defer(millis = 2000)]` defers the interaction if the command didn't acknowledge
+/// it after the given amount of milliseconds, so slow commands don't fail before responding.
+///
+/// ## Locale restrictions
+///
+/// A command can be restricted to some locales using `#[restrict_locales("en-US", "en-GB")]`,
+/// users with any other locale won't be able to execute it.
+///
+/// ## Requiring one of many arguments
+///
+/// Using `#[require_one_of("name", "color")]` prevents the command from executing unless at least
+/// one of the given arguments is provided, referred by the name they are registered with.
+///
+/// ## Direct messages
+///
+/// By default commands can be used in direct messages, to make a command only available inside
+/// guilds, use `#[only_guilds]` or `#[dm_permission = false]`.
+///
+/// ## Installation contexts
+///
+/// Using `#[integration_types(guild_install, user_install)]` sets where the application must be
+/// installed for the command to be available, and `#[contexts(guild, bot_dm, private_channel)]`
+/// sets where the command can be used.
 #[proc_macro_attribute]
 pub fn command(attrs: TokenStream, input: TokenStream) -> TokenStream {
     extract(command::command(attrs.into(), input.into()))
 }
 
+/// Converts an `async-compatible` function into a message command, shown in the context menu of
+/// messages. This works the same way as the [command](macro@command) macro, except that no
+/// description is needed and the arguments after the context receive the targeted message, which
+/// can be either a [`Message`] or an `Id<MessageMarker>`.
+///
+/// The name of the command defaults to the function name, but it can be set using
+/// `#[message_command("Command name")]` or `#[message_command(name = "Command name")]`.
+///
+/// # Examples:
+///
+/// ```rust
+/// use vesper::prelude::*;
+/// use vesper::twilight_exports::Message;
+///
+/// #[message_command("Quote message")]
+/// async fn quote(ctx: &mut SlashContext<()>, message: Message) -> DefaultCommandResult {
+///     ctx.respond()
+///         .content(format!("> {}", message.content))
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Message`]: https://docs.rs/twilight-model/latest/twilight_model/channel/message/struct.Message.html
+#[proc_macro_attribute]
+pub fn message_command(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    extract(command::message_command(attrs.into(), input.into()))
+}
+
+/// Converts an `async-compatible` function into a user command, shown in the context menu of
+/// users. This works the same way as the [message_command](macro@message_command) macro, but the
+/// arguments after the context receive the targeted user, which can be a [`User`] or an
+/// `Id<UserMarker>`, and the targeted member, as an `Option<InteractionMember>`, which is only
+/// present when the command is used inside a guild.
+///
+/// # Examples:
+///
+/// ```rust
+/// use vesper::prelude::*;
+/// use vesper::twilight_exports::{InteractionMember, User};
+///
+/// #[user_command("User info")]
+/// async fn user_info(
+///     ctx: &mut SlashContext<()>,
+///     user: User,
+///     member: Option<InteractionMember>
+/// ) -> DefaultCommandResult {
+///     let nick = member.and_then(|member| member.nick).unwrap_or_else(|| user.name.clone());
+///
+///     ctx.respond()
+///         .content(format!("{} is known as {}", user.name, nick))
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`User`]: https://docs.rs/twilight-model/latest/twilight_model/user/struct.User.html
+#[proc_macro_attribute]
+pub fn user_command(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    extract(command::user_command(attrs.into(), input.into()))
+}
+
 /// Prepares the function to allow it to be set as an after hook, see
 /// the implementation for more information about this macro's behaviour.
 #[proc_macro_attribute]
@@ -118,11 +243,65 @@ pub fn check(_: TokenStream, input: TokenStream) -> TokenStream {
     extract(check::check(input.into()))
 }
 
+/// Prepares the function to allow it to be set as an execution hook, see
+/// the implementation for more information about this macro's behaviour.
+#[proc_macro_attribute]
+pub fn execution(_: TokenStream, input: TokenStream) -> TokenStream {
+    extract(execution::execution(input.into()))
+}
+
+/// Prepares the function to be used as the handler of unknown commands, invoked when discord
+/// sends a command which isn't provided to the framework, like a leftover of a previous version.
+/// The function must take a context and a `&str` containing the full name of the command.
+///
+/// # Examples:
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[unknown_command]
+/// async fn outdated(ctx: &mut SlashContext<()>, command_name: &str) {
+///     let _ = ctx.respond()
+///         .content(format!("The command {command_name} is outdated, please wait for it to update"))
+///         .ephemeral()
+///         .await;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn unknown_command(_: TokenStream, input: TokenStream) -> TokenStream {
+    extract(unknown_command::unknown_command(input.into()))
+}
+
 #[proc_macro_attribute]
 pub fn error_handler(_: TokenStream, input: TokenStream) -> TokenStream {
     extract(error_handler::error_handler(input.into()))
 }
 
+/// Prepares the function to be used as a component handler, invoked when a message component
+/// whose custom id starts with the prefix it was registered with is used. The function must take
+/// a context and a `&str` containing the rest of the custom id after the `:` separator, and
+/// return the same type as commands do.
+///
+/// # Examples:
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// // Registered with `.component("page", show_page)`, so it handles custom ids like `page:5`.
+/// #[component]
+/// async fn show_page(ctx: &mut SlashContext<()>, page: &str) -> DefaultCommandResult {
+///     ctx.respond()
+///         .content(format!("This is the page {page}"))
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn component(_: TokenStream, input: TokenStream) -> TokenStream {
+    extract(component::component(input.into()))
+}
+
 /// Prepares the function to be used to autocomplete command arguments.
 #[proc_macro_attribute]
 pub fn autocomplete(_: TokenStream, input: TokenStream) -> TokenStream {
@@ -170,6 +349,58 @@ pub fn parse(input: TokenStream) -> TokenStream {
     extract(parse::parse(input.into()))
 }
 
+/// Implements `Parse` for an enum using discord choices, allowing it to be used as a command
+/// argument where the user has to select one of the variants. Unlike the `Parse` derive, the
+/// value of each choice can be customized, and can be either a string, an integer or a number.
+///
+/// The derive also generates an inherent `choices()` function returning the choices used to
+/// register the argument.
+///
+/// # Examples:
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[derive(Choice)]
+/// enum Color {
+///     Red, // <- This item will have "Red" both as name and value
+///     #[choice(name = "Light green", value = "green")]
+///     Green,
+///     #[choice(name_localizations("es-ES" = "Azul"))]
+///     Blue,
+/// }
+///
+/// #[derive(Choice)]
+/// enum Speed {
+///     #[choice(value = 1)]
+///     Slow,
+///     #[choice(value = 2)]
+///     Fast,
+/// }
+/// ```
+///
+/// # Attributes
+///
+/// - `#[choice(name = "<NAME>")]`: The name of the choice seen by the user, by default the
+///   name of the variant.
+///
+/// - `#[choice(value = <VALUE>)]`: The value of the choice, either a string, an integer or a
+///   number, by default the name of the choice. All variants must have the same value type, which
+///   determines the type of the argument.
+///
+/// - `#[choice(name_localizations("<LOCALE>" = "<NAME>", ...))]`: The localized names of the
+///   choice.
+///
+/// - `#[choice(other)]`: Marks a variant containing a `String`, like `Other(String)`, which
+///   receives the values not matching any other choice. Discord only accepts the registered
+///   choices, so enums with this variant don't register them, allowing any input. The choices
+///   returned by `choices()` can be suggested using autocomplete instead. Only string choices
+///   support this variant.
+#[proc_macro_derive(Choice, attributes(choice))]
+pub fn choice(input: TokenStream) -> TokenStream {
+    extract(choice::choice(input.into()))
+}
+
 /// Implements the `Modal` trait for the derived struct, allowing it to create modals and collect
 /// the inputs provided by the user.
 ///
@@ -115,11 +115,10 @@ pub fn parse(input: TokenStream2) -> Result<TokenStream2> {
                     let num = usize::parse(http_client, data, value, resolved).await?;
                     match num {
                         #parse_stream
-                        _ => return Err(ParseError::Parsing {
+                        _ => return Err(ParseError::OutOfRange {
                                 argument_name: String::new(),
-                                required: true,
                                 argument_type: String::from(stringify!(#enum_name)),
-                                error: String::from("Unrecognized option")
+                                error: String::from("The value is not one of the choices")
                             }
                         )
                     }
@@ -0,0 +1,69 @@
+//! Measures the time spent finding the command to execute in a framework with 100 commands,
+//! comparing the default hasher with the one used by the framework maps.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use tokio::runtime::Runtime;
+use vesper::{command::CommandMap, hash::BuildNameHasher, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::Id;
+
+const COMMANDS: usize = 100;
+
+#[command]
+#[description = "Does nothing"]
+async fn noop(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+/// Names similar to the ones used by bots, like `command-42`.
+fn names() -> Vec<&'static str> {
+    (0..COMMANDS)
+        .map(|index| &*Box::leak(format!("command-{}", index).into_boxed_str()))
+        .collect()
+}
+
+fn lookup<S: BuildHasher + Default>(criterion: &mut Criterion, hasher: &str, names: &[&'static str]) {
+    let map = names.iter()
+        .enumerate()
+        .map(|(index, name)| (*name, index))
+        .collect::<HashMap<_, _, S>>();
+
+    criterion.bench_function(&format!("lookup/{}", hasher), |bencher| {
+        bencher.iter(|| {
+            for name in names {
+                black_box(map.get(black_box(name)));
+            }
+        })
+    });
+}
+
+fn lookups(criterion: &mut Criterion) {
+    let names = names();
+
+    lookup::<RandomState>(criterion, "siphash", &names);
+    lookup::<BuildNameHasher>(criterion, "fnv", &names);
+}
+
+fn process(criterion: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start()).unwrap();
+
+    let mut builder = Framework::builder(server.client(), Id::new(1), ());
+    let mut commands = CommandMap::default();
+    for name in names() {
+        commands.insert(name, noop().name(name));
+    }
+    builder.commands = commands;
+    let framework = builder.build();
+
+    let interaction = TestInteraction::new(format!("command-{}", COMMANDS / 2)).interaction();
+
+    criterion.bench_function("process", |bencher| {
+        bencher.iter(|| runtime.block_on(framework.process(black_box(interaction.clone()))))
+    });
+}
+
+criterion_group!(benches, lookups, process);
+criterion_main!(benches);
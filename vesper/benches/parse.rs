@@ -0,0 +1,54 @@
+//! Measures the time spent parsing the arguments of a command with 10 arguments.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tokio::runtime::Runtime;
+use vesper::{prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{CommandOptionValue, Id};
+
+#[command]
+#[description = "Takes 10 arguments"]
+async fn ten(
+    _ctx: &SlashContext<()>,
+    #[description = "A string"] first: String,
+    #[description = "A string"] second: String,
+    #[description = "A string"] third: String,
+    #[description = "An integer"] fourth: i64,
+    #[description = "An integer"] fifth: i64,
+    #[description = "An integer"] sixth: i64,
+    #[description = "A boolean"] seventh: bool,
+    #[description = "A boolean"] eighth: bool,
+    #[description = "A number"] ninth: f64,
+    #[description = "A number"] tenth: f64
+) -> DefaultCommandResult {
+    black_box((first, second, third, fourth, fifth, sixth, seventh, eighth, ninth, tenth));
+    Ok(())
+}
+
+fn parse(criterion: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start()).unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(ten)
+        .build();
+
+    // Provide the options in reverse order, like discord may do.
+    let interaction = TestInteraction::new("ten")
+        .option("tenth", CommandOptionValue::Number(10.0))
+        .option("ninth", CommandOptionValue::Number(9.0))
+        .option("eighth", CommandOptionValue::Boolean(false))
+        .option("seventh", CommandOptionValue::Boolean(true))
+        .option("sixth", CommandOptionValue::Integer(6))
+        .option("fifth", CommandOptionValue::Integer(5))
+        .option("fourth", CommandOptionValue::Integer(4))
+        .option("third", CommandOptionValue::String(String::from("third")))
+        .option("second", CommandOptionValue::String(String::from("second")))
+        .option("first", CommandOptionValue::String(String::from("first")))
+        .interaction();
+
+    criterion.bench_function("parse 10 arguments", |bencher| {
+        bencher.iter(|| runtime.block_on(framework.process(black_box(interaction.clone()))))
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
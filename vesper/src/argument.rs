@@ -20,7 +20,16 @@ pub struct CommandArgument<D, T, E> {
     pub choices: Option<Vec<CommandOptionChoice>>,
     /// A function used to autocomplete fields.
     pub autocomplete: Option<AutocompleteHook<D>>,
-    pub modify_fn: fn(&mut CommandOption)
+    /// Whether the argument is registered with autocomplete enabled, only these arguments have
+    /// their autocomplete interactions routed to the [handler](Self::autocomplete).
+    pub autocomplete_enabled: bool,
+    pub modify_fn: fn(&mut CommandOption),
+    /// The maximum amount of values this argument accepts, only present if it is variadic.
+    pub variadic: Option<usize>,
+    /// The minimum length of the input, only valid for string arguments.
+    pub min_length: Option<u16>,
+    /// The maximum length of the input, only valid for string arguments.
+    pub max_length: Option<u16>
 }
 
 impl<D, T, E> CommandArgument<D, T, E> {
@@ -45,9 +54,14 @@ impl<D, T, E> CommandArgument<D, T, E> {
 
         (self.modify_fn)(&mut option);
 
+        if option.kind == CommandOptionType::String {
+            option.min_length = self.min_length.or(option.min_length);
+            option.max_length = self.max_length.or(option.max_length);
+        }
+
         match option.kind {
             CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number => {
-                option.autocomplete = Some(self.autocomplete.is_some());
+                option.autocomplete = Some(self.autocomplete_enabled);
                 option.choices = Some(self.choices.clone().unwrap_or_default());
             },
             _ => ()
@@ -55,6 +69,41 @@ impl<D, T, E> CommandArgument<D, T, E> {
 
         option
     }
+
+    /// Returns whether the option with the given name was registered by this argument, variadic
+    /// arguments register one option per value, named `<name>_1`, `<name>_2`...
+    pub(crate) fn registers_option(&self, option: &str) -> bool {
+        let Some(max) = self.variadic else {
+            return self.name == option;
+        };
+
+        option.strip_prefix(self.name)
+            .and_then(|suffix| suffix.strip_prefix('_'))
+            .and_then(|index| index.parse::<usize>().ok())
+            .is_some_and(|index| (1..=max).contains(&index))
+    }
+
+    /// Converts the argument into all the twilight's [command options](CommandOption) it needs to
+    /// be registered, variadic arguments are expanded into one option per accepted value.
+    pub fn as_options(&self, f: &Framework<D, T, E>, c: &crate::command::Command<D, T, E>) -> Vec<CommandOption> {
+        let option = self.as_option(f, c);
+
+        let Some(max) = self.variadic else {
+            return vec![option];
+        };
+
+        (1..=max).map(|index| {
+            let mut option = option.clone();
+            option.name = format!("{}_{}", option.name, index);
+            option.required = Some(index == 1 && self.required);
+            if let Some(localizations) = &mut option.name_localizations {
+                for name in localizations.values_mut() {
+                    *name = format!("{}_{}", name, index);
+                }
+            }
+            option
+        }).collect()
+    }
 }
 
 impl<D: Send + Sync, T, E> CommandArgument<D, T, E> {
@@ -72,11 +121,45 @@ impl<D: Send + Sync, T, E> CommandArgument<D, T, E> {
             required: Arg::required(),
             kind: Arg::kind(),
             choices: Arg::choices(),
+            autocomplete_enabled: autocomplete.is_some(),
             autocomplete,
-            modify_fn: Arg::modify_option
+            modify_fn: Arg::modify_option,
+            variadic: Arg::variadic(),
+            min_length: None,
+            max_length: None
         }
     }
 
+    /// Sets whether the argument is required, overriding the value inferred from its type.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets whether the argument is registered with autocomplete enabled, overriding the value
+    /// inferred from the presence of a handler.
+    ///
+    /// This allows enabling autocomplete for arguments whose handler is registered separately,
+    /// using [FrameworkBuilder::autocomplete](crate::builder::FrameworkBuilder::autocomplete), or
+    /// disabling it even if the argument has a handler. Autocomplete interactions of arguments
+    /// without autocomplete enabled are ignored.
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete_enabled = autocomplete;
+        self
+    }
+
+    /// Sets the minimum length of the input, only valid for string arguments.
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Sets the maximum length of the input, only valid for string arguments.
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     pub fn localized_names<I, K, V>(mut self, iterator: I) -> Self
     where
         I: IntoIterator<Item = (K, V)>,
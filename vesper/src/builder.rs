@@ -2,14 +2,27 @@ use crate::{
     command::{Command, CommandMap},
     framework::{DefaultError, Framework},
     group::*,
-    hook::{AfterHook, BeforeHook},
-    twilight_exports::{ApplicationMarker, Client, CommandType, Id, Permissions},
-    parse::ParseError
+    hook::{
+        AfterHook, AutocompleteHook, BeforeHook, CheckHook, ComponentHook, ErrorHandlerHook, ExecutionHook,
+        UnknownCommandHook
+    },
+    twilight_exports::{
+        ApplicationMarker, Client, CommandType, GuildMarker, Id, InteractionClient, InteractionResponseData,
+        Permissions, UserMarker
+    },
+    error::{FetchApplicationError, FetchOwnersError},
+    parse::ParseError,
+    router::ComponentMap
 };
 
-use std::{ops::Deref, sync::Arc};
+use std::{collections::{HashMap, HashSet}, ops::Deref, sync::Arc};
 
 /// A wrapper around twilight's http client allowing the user to decide how to provide it to the framework.
+///
+/// The http client is `Send + Sync` and handles ratelimits internally, so it can be shared with code
+/// outside of the framework, making requests concurrently from any task. To keep using the client
+/// after giving it to the framework, provide it as an [Arc](WrappedClient::Arc) and keep a clone of
+/// it, or borrow it using [inner](WrappedClient::inner).
 #[allow(clippy::large_enum_variant)]
 pub enum WrappedClient {
     Arc(Arc<Client>),
@@ -27,6 +40,21 @@ impl WrappedClient {
         }
     }
 
+    /// Returns the [interaction client](InteractionClient) of the underlying http client for the
+    /// given application.
+    pub fn interaction(&self, application_id: Id<ApplicationMarker>) -> InteractionClient<'_> {
+        self.inner().interaction(application_id)
+    }
+
+    /// Returns the shared http client if it was provided as an [Arc](WrappedClient::Arc), which
+    /// can be cloned to keep using the client outside of the framework.
+    pub fn as_arc(&self) -> Option<&Arc<Client>> {
+        match self {
+            Self::Arc(c) => Some(c),
+            _ => None
+        }
+    }
+
     /// Casts the [client](WrappedClient) into T if it's [Boxed](WrappedClient::Boxed)
     ///
     /// **SAFETY: The caller must ensure the type given is the same as the boxed one.**
@@ -76,12 +104,37 @@ pub struct FrameworkBuilder<D, T = (), E = DefaultError> {
     pub data: D,
     /// The actual commands, only the simple ones.
     pub commands: CommandMap<D, T, E>,
+    /// The message commands, shown in the context menu of messages.
+    pub message_commands: CommandMap<D, T, E>,
+    /// The user commands, shown in the context menu of users.
+    pub user_commands: CommandMap<D, T, E>,
     /// All groups containing commands.
     pub groups: GroupParentMap<D, T, E>,
-    /// A hook executed before any command.
-    pub before: Option<BeforeHook<D>>,
-    /// A hook executed after command's completion.
-    pub after: Option<AfterHook<D, T, E>>,
+    /// The hooks executed before any command, in registration order.
+    pub before: Vec<BeforeHook<D>>,
+    /// The hooks executed after command's completion, in registration order.
+    pub after: Vec<AfterHook<D, T, E>>,
+    /// A hook executed after processing any command, whatever the outcome was.
+    pub execution: Option<ExecutionHook<D, T, E>>,
+    /// The error handler used by the commands which don't have one.
+    pub error_handler: Option<ErrorHandlerHook<D, E>>,
+    /// The response sent when a command raises an error which isn't handled by any error handler.
+    pub error_response: Option<InteractionResponseData>,
+    /// A hook executed when discord sends a command not provided to the framework.
+    pub unknown_command: Option<UnknownCommandHook<D>>,
+    /// The handlers of message components, by the custom id prefix they handle.
+    pub components: ComponentMap<D, T, E>,
+    /// The owners of the bot, used by the [owners](crate::checks::owners) check.
+    pub owners: HashSet<Id<UserMarker>>,
+    /// Whether the required permissions of commands are enforced by the framework.
+    pub enforce_permissions: bool,
+    /// Whether commands are only logged instead of being registered in discord.
+    pub dry_run: bool,
+    /// The maximum amount of attempts made to register each command.
+    pub registration_attempts: u32,
+    /// The autocomplete handlers registered independently of the commands, by the full name of
+    /// the command and the name of the argument.
+    pub autocompletes: HashMap<(&'static str, &'static str), AutocompleteHook<D>>,
 }
 
 impl<D, T, E> FrameworkBuilder<D, T, E>
@@ -99,13 +152,47 @@ where
             application_id,
             data,
             commands: Default::default(),
+            message_commands: Default::default(),
+            user_commands: Default::default(),
             groups: Default::default(),
-            before: None,
-            after: None,
+            before: Vec::new(),
+            after: Vec::new(),
+            execution: None,
+            error_handler: None,
+            error_response: None,
+            unknown_command: None,
+            components: Default::default(),
+            owners: Default::default(),
+            enforce_permissions: false,
+            dry_run: false,
+            registration_attempts: 3,
+            autocompletes: Default::default(),
         }
     }
 
-    /// Set the hook that will be executed before commands.
+    /// Creates a new [Builder](self::FrameworkBuilder), fetching the id of the application the
+    /// client belongs to from discord.
+    ///
+    /// The id is only fetched once and stored in the framework, where it is available using
+    /// [application_id](crate::framework::Framework::application_id).
+    pub async fn fetch_application_id(
+        http_client: impl Into<WrappedClient>,
+        data: D
+    ) -> Result<Self, FetchApplicationError> {
+        let http_client = http_client.into();
+        let application = http_client.inner()
+            .current_user_application()
+            .await?
+            .model()
+            .await?;
+
+        Ok(Self::new(http_client, application.id, data))
+    }
+
+    /// Adds a hook that will be executed before commands.
+    ///
+    /// Multiple hooks can be added, they are executed in the order they were added until one of
+    /// them returns `false`, in which case the rest of them and the command are not executed.
     ///
     /// # Examples
     ///
@@ -132,11 +219,15 @@ where
     /// }
     /// ```
     pub fn before(mut self, fun: FnPointer<BeforeHook<D>>) -> Self {
-        self.before = Some(fun());
+        self.before.push(fun());
         self
     }
 
-    /// Set the hook that will be executed after command's completion.
+    /// Adds a hook that will be executed after command's completion.
+    ///
+    /// Multiple hooks can be added, all of them are executed in the order they were added. Since
+    /// the output of the command is given by value, only the first hook receives it, while the
+    /// rest of them receive `None`.
     ///
     /// # Examples
     ///
@@ -146,7 +237,7 @@ where
     /// use twilight_model::id::Id;
     ///
     /// #[after]
-    /// async fn after_hook(ctx: &mut SlashContext<()>, command_name: &str, _: Option<DefaultCommandResult>) {
+    /// async fn after_hook(ctx: &mut SlashContext<()>, command_name: &str, _: Option<&DefaultCommandResult>) {
     ///     println!("Command {command_name} finished execution");
     /// }
     ///
@@ -162,7 +253,218 @@ where
     /// }
     /// ```
     pub fn after(mut self, fun: FnPointer<AfterHook<D, T, E>>) -> Self {
-        self.after = Some(fun());
+        self.after.push(fun());
+        self
+    }
+
+    /// Set the hook that will be executed once a command finishes processing, receiving its
+    /// [execution result](crate::command::ExecutionResult). This hook is executed for every
+    /// [execution state](crate::command::ExecutionState), including failed checks and cooldowns.
+    ///
+    /// If the output of the command was still present, it is moved into the hook, leaving
+    /// [TakenByExecutionHook](crate::command::OutputLocation::TakenByExecutionHook) in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::{command::{ExecutionResult, ExecutionState}, framework::DefaultError, prelude::*};
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// #[execution]
+    /// async fn log_execution(
+    ///     ctx: &mut SlashContext<()>,
+    ///     command_name: &str,
+    ///     result: ExecutionResult<(), DefaultError>
+    /// ) {
+    ///     if let ExecutionState::CheckFailed = result.state {
+    ///         println!("Someone was denied from using {command_name}");
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let token = std::env::var("DISCORD_TOKEN").unwrap();
+    ///     let app_id = std::env::var("DISCORD_APP_ID").unwrap().parse::<u64>().unwrap();
+    ///     let http_client = Client::new(token);
+    ///
+    ///     let framework = Framework::<()>::builder(http_client, Id::new(app_id), ())
+    ///         .execution(log_execution)
+    ///         .build();
+    /// }
+    /// ```
+    pub fn execution(mut self, fun: FnPointer<ExecutionHook<D, T, E>>) -> Self {
+        self.execution = Some(fun());
+        self
+    }
+
+    /// Set the error handler used when a command or its checks return an error and neither the
+    /// command nor its group have an error handler.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::{framework::DefaultError, prelude::*};
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// #[error_handler]
+    /// async fn handle_errors(ctx: &mut SlashContext<()>, error: DefaultError) {
+    ///     println!("A command raised an error: {error}");
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let token = std::env::var("DISCORD_TOKEN").unwrap();
+    ///     let app_id = std::env::var("DISCORD_APP_ID").unwrap().parse::<u64>().unwrap();
+    ///     let http_client = Client::new(token);
+    ///
+    ///     let framework = Framework::<()>::builder(http_client, Id::new(app_id), ())
+    ///         .error_handler(handle_errors)
+    ///         .build();
+    /// }
+    /// ```
+    pub fn error_handler(mut self, fun: FnPointer<ErrorHandlerHook<D, E>>) -> Self {
+        self.error_handler = Some(fun());
+        self
+    }
+
+    /// Sets the response sent when a command or one of its checks raises an error and there
+    /// isn't any [error handler](Self::error_handler) to handle it, so the user is told something
+    /// went wrong instead of the interaction failing.
+    ///
+    /// If the interaction was deferred, the original response is edited to show the content and
+    /// embeds of the error response. Responses sent by the command before failing are left
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    /// use twilight_http::Client;
+    /// use twilight_model::channel::message::MessageFlags;
+    /// use twilight_model::http::interaction::InteractionResponseData;
+    /// use twilight_model::id::Id;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let token = std::env::var("DISCORD_TOKEN").unwrap();
+    ///     let app_id = std::env::var("DISCORD_APP_ID").unwrap().parse::<u64>().unwrap();
+    ///     let http_client = Client::new(token);
+    ///
+    ///     let framework = Framework::<()>::builder(http_client, Id::new(app_id), ())
+    ///         .error_response(InteractionResponseData {
+    ///             content: Some(String::from("Something went wrong")),
+    ///             flags: Some(MessageFlags::EPHEMERAL),
+    ///             ..Default::default()
+    ///         })
+    ///         .build();
+    /// }
+    /// ```
+    pub fn error_response(mut self, response: InteractionResponseData) -> Self {
+        self.error_response = Some(response);
+        self
+    }
+
+    /// Sets whether the framework checks the [required permissions](Command::required_permissions)
+    /// of commands and groups against the permissions of the invoking member before executing
+    /// them, failing with [CheckFailed](crate::command::ExecutionState::CheckFailed) if the member
+    /// lacks any of them. Since there are no member permissions outside of guilds, those commands
+    /// always fail when used in direct messages.
+    ///
+    /// By default, required permissions are only registered in discord as the default member
+    /// permissions, which server administrators can override.
+    pub fn enforce_permissions(mut self, enforce: bool) -> Self {
+        self.enforce_permissions = enforce;
+        self
+    }
+
+    /// Sets whether the framework runs in dry run mode, in which the `register_*` methods log the
+    /// commands they would register at info level and return them without calling discord.
+    ///
+    /// The returned commands don't have an id, since they are never created. Syncing the commands
+    /// only logs the commands it would create and delete.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the maximum amount of attempts made to register each command, defaults to 3.
+    ///
+    /// Registrations failing because of ratelimits, server errors or connection problems are
+    /// retried with an exponential backoff of up to a minute, waiting the time requested by
+    /// discord when ratelimited. Once all the attempts fail, the `register_*` methods return a
+    /// [Registration](crate::error::CreateCommandError::Registration) error.
+    pub fn registration_attempts(mut self, attempts: u32) -> Self {
+        self.registration_attempts = attempts.max(1);
+        self
+    }
+
+    /// Adds the given users as owners of the bot, allowing them to use the commands restricted
+    /// by the [owners](crate::checks::owners) check.
+    pub fn owners(mut self, owners: impl IntoIterator<Item = Id<UserMarker>>) -> Self {
+        self.owners.extend(owners);
+        self
+    }
+
+    /// Fetches the owners of the application from discord and adds them as owners of the bot,
+    /// if the application belongs to a team, all the team members are added.
+    pub async fn fetch_owners(mut self) -> Result<Self, FetchOwnersError> {
+        let application = self.http_client.inner()
+            .current_user_application()
+            .await?
+            .model()
+            .await?;
+
+        match application.team {
+            Some(team) => self.owners.extend(team.members.into_iter().map(|member| member.user.id)),
+            None => self.owners.extend(application.owner.map(|owner| owner.id))
+        }
+
+        Ok(self)
+    }
+
+    /// Set the hook executed when discord sends a command which isn't provided to the framework,
+    /// like a global command left by a previous version of the bot. This allows to tell the user
+    /// the command is outdated instead of letting the interaction fail.
+    pub fn unknown_command(mut self, fun: FnPointer<UnknownCommandHook<D>>) -> Self {
+        self.unknown_command = Some(fun());
+        self
+    }
+
+    /// Registers a handler for the message components whose custom id is the given prefix or
+    /// starts with the prefix followed by `:`, the rest of the custom id is given to the handler.
+    ///
+    /// Components awaited using [waiters](crate::wait::InteractionWaiter) are not given to
+    /// the handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use vesper::prelude::*;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// #[component]
+    /// async fn show_page(ctx: &mut SlashContext<()>, page: &str) -> DefaultCommandResult {
+    ///     // Show the page given by a button with `page:<number>` as custom id.
+    ///     Ok(())
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let framework = Framework::builder(Arc::new(Client::new(String::from("token"))), Id::new(1), ())
+    ///         .component("page", show_page)
+    ///         .build();
+    /// }
+    /// ```
+    pub fn component(mut self, prefix: &'static str, fun: FnPointer<ComponentHook<D, T, E>>) -> Self {
+        assert!(
+            !prefix.contains(crate::router::CUSTOM_ID_SEPARATOR),
+            "Component prefix [{}] can't contain the separator", prefix
+        );
+        self.components.insert(prefix, fun());
         self
     }
 
@@ -178,8 +480,8 @@ where
     ///#[command]
     ///#[description = "Says Hello world!"]
     ///async fn hello_world(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
-    ///     ctx.defer(false).await?;
-    ///     ctx.interaction_client.update_response(&ctx.interaction.token)
+    ///     ctx.defer().await?;
+    ///     ctx.update_response()
     ///         .content(Some("Hello world!"))
     ///         .unwrap()
     ///         .await?;
@@ -194,8 +496,8 @@ where
     ///     #[rename = "content"] #[description = "The content"] c: String
     ///) -> DefaultCommandResult
     ///{
-    ///     ctx.defer(false).await?;
-    ///     ctx.interaction_client.update_response(&ctx.interaction.token)
+    ///     ctx.defer().await?;
+    ///     ctx.update_response()
     ///         .content(Some(&c))
     ///         .unwrap()
     ///         .await?;
@@ -214,8 +516,28 @@ where
     ///         .build();
     /// }
     /// ```
-    pub fn command(mut self, fun: FnPointer<Command<D, T, E>>) -> Self {
-        let cmd = fun();
+    pub fn command(self, fun: FnPointer<Command<D, T, E>>) -> Self {
+        self.insert_command(fun())
+    }
+
+    fn insert_command(mut self, mut cmd: Command<D, T, E>) -> Self {
+        cmd.order_arguments();
+
+        // Context menu commands have their own namespace, so they can share names with chat commands.
+        let context_menu = match cmd.kind {
+            CommandType::Message => Some(&mut self.message_commands),
+            CommandType::User => Some(&mut self.user_commands),
+            _ => None
+        };
+
+        if let Some(map) = context_menu {
+            if map.contains_key(cmd.name) {
+                panic!("{} already registered", cmd.name);
+            }
+            map.insert(cmd.name, cmd);
+            return self;
+        }
+
         if self.commands.contains_key(cmd.name) || self.groups.contains_key(cmd.name) {
             panic!("{} already registered", cmd.name);
         }
@@ -223,15 +545,64 @@ where
         self
     }
 
+    /// Registers a new command only available in the given guilds.
+    ///
+    /// These commands are registered in their guilds when using
+    /// [register_commands](Framework::register_commands), while the rest are registered
+    /// globally. This is useful to test commands, as guild commands are updated instantly.
+    pub fn guild_command<I>(self, guilds: I, fun: FnPointer<Command<D, T, E>>) -> Self
+    where
+        I: IntoIterator<Item = Id<GuildMarker>>
+    {
+        self.insert_command(fun().guilds(guilds))
+    }
+
     /// Registers a new group of commands.
-    pub fn group<F>(mut self, fun: F) -> Self
+    pub fn group<F>(self, fun: F) -> Self
     where
         F: FnOnce(&mut GroupParentBuilder<D, T, E>) -> &mut GroupParentBuilder<D, T, E>,
     {
         let mut builder = GroupParentBuilder::new();
         fun(&mut builder);
-        let group = builder.build();
+        self.add_group(builder.build())
+    }
 
+    /// Registers an already built group of commands, allowing to create groups at runtime using a
+    /// [GroupParentBuilder] instead of a closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::{builder::{CommandGroupBuilder, GroupParentBuilder}, framework::DefaultError, group::GroupParent, prelude::*};
+    ///
+    /// #[command]
+    /// #[description = "Shows a setting"]
+    /// async fn get(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[command]
+    /// #[description = "Resets all settings"]
+    /// async fn reset(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
+    ///     Ok(())
+    /// }
+    ///
+    /// fn settings() -> GroupParent<(), (), DefaultError> {
+    ///     let mut subgroup = CommandGroupBuilder::new();
+    ///     subgroup.name("display")
+    ///         .description("Display settings")
+    ///         .add_command(get());
+    ///
+    ///     let mut builder = GroupParentBuilder::new();
+    ///     builder.name("settings")
+    ///         .description("Manage the settings")
+    ///         .add_command(reset())
+    ///         .add_group(subgroup);
+    ///
+    ///     builder.build()
+    /// }
+    /// ```
+    pub fn add_group(mut self, group: GroupParent<D, T, E>) -> Self {
         if self.commands.contains_key(group.name) || self.groups.contains_key(group.name) {
             panic!("{} already registered", group.name);
         }
@@ -240,32 +611,125 @@ where
         self
     }
 
+    /// Registers an autocomplete handler for the given argument of the given command, allowing to
+    /// reuse the same handler across multiple commands. Subcommands are referred by their full
+    /// name, as shown in discord, like `config set`.
+    ///
+    /// This overrides the handler set in the definition of the argument, if any, and enables
+    /// autocomplete for the argument.
+    ///
+    /// # Panics
+    ///
+    /// Building the framework panics if the command or the argument don't exist.
+    pub fn autocomplete(
+        mut self,
+        command: &'static str,
+        argument: &'static str,
+        fun: FnPointer<AutocompleteHook<D>>
+    ) -> Self {
+        self.autocompletes.insert((command, argument), fun());
+        self
+    }
+
+    /// Gets a mutable reference to the chat command with the given full name.
+    fn command_mut(&mut self, name: &str) -> Option<&mut Command<D, T, E>> {
+        let mut parts = name.split_whitespace();
+        let first = parts.next()?;
+
+        let Some(group) = self.groups.get_mut(first) else {
+            return match parts.next() {
+                Some(_) => None,
+                None => self.commands.get_mut(first)
+            };
+        };
+
+        match (parts.next()?, parts.next(), parts.next()) {
+            (subcommand, None, _) => group.kind.as_simple_mut()?.get_mut(subcommand),
+            (subgroup, Some(subcommand), None) => group.kind.as_group_mut()?
+                .get_mut(subgroup)?
+                .subcommands
+                .get_mut(subcommand),
+            _ => None
+        }
+    }
+
     /// Builds the framework, returning a [Framework](crate::framework::Framework).
-    pub fn build(self) -> Framework<D, T, E> {
+    ///
+    /// This doesn't make any request to discord, so the framework can process interactions right
+    /// away. Commands must be registered explicitly, using
+    /// [register_global_commands](Framework::register_global_commands) or any of the other
+    /// `register_*` methods, which can be skipped if they were already registered.
+    pub fn build(mut self) -> Framework<D, T, E> {
+        for ((command, argument), hook) in std::mem::take(&mut self.autocompletes) {
+            let target = self.command_mut(command)
+                .and_then(|command| command.arguments.iter_mut().find(|arg| arg.name == argument));
+
+            match target {
+                Some(target) => {
+                    target.autocomplete = Some(hook);
+                    target.autocomplete_enabled = true;
+                },
+                None => panic!("Autocomplete target [{} {}] not found", command, argument)
+            }
+        }
+
         Framework::from_builder(self)
     }
 }
 
+fn extend_localizations<I, K, V>(localizations: &mut Option<HashMap<String, String>>, iterator: I)
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: ToString,
+    V: ToString
+{
+    localizations
+        .get_or_insert_with(HashMap::new)
+        .extend(iterator.into_iter().map(|(k, v)| (k.to_string(), v.to_string())));
+}
+
 /// A builder of a [group parent](crate::group::GroupParent), see it for documentation.
+///
+/// Besides being used by [FrameworkBuilder::group], it can be created directly to assemble groups
+/// at runtime, adding them using [FrameworkBuilder::add_group]. Names and descriptions must be
+/// `'static`, so the ones created at runtime have to be leaked, using [Box::leak] for example.
 pub struct GroupParentBuilder<D, T, E> {
     name: Option<&'static str>,
     description: Option<&'static str>,
+    localized_names: Option<HashMap<String, String>>,
+    localized_descriptions: Option<HashMap<String, String>>,
     kind: ParentType<D, T, E>,
     required_permissions: Option<Permissions>,
     nsfw: bool,
-    only_guilds: bool
+    only_guilds: bool,
+    checks: Vec<CheckHook<D, E>>,
+    error_handler: Option<ErrorHandlerHook<D, E>>,
+    guilds: Vec<Id<GuildMarker>>,
+    default_subcommand: Option<&'static str>
+}
+
+impl<D, T, E> Default for GroupParentBuilder<D, T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<D, T, E> GroupParentBuilder<D, T, E> {
     /// Creates a new builder.
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             name: None,
             description: None,
+            localized_names: None,
+            localized_descriptions: None,
             kind: ParentType::Group(Default::default()),
             required_permissions: None,
             nsfw: false,
-            only_guilds: false
+            only_guilds: false,
+            checks: Vec::new(),
+            error_handler: None,
+            guilds: Vec::new(),
+            default_subcommand: None
         }
     }
 
@@ -281,6 +745,30 @@ impl<D, T, E> GroupParentBuilder<D, T, E> {
         self
     }
 
+    /// Adds localized names to this parent group, the [name](Self::name) is used as fallback for the
+    /// locales not provided.
+    pub fn localized_names<I, K, V>(&mut self, iterator: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString
+    {
+        extend_localizations(&mut self.localized_names, iterator);
+        self
+    }
+
+    /// Adds localized descriptions to this parent group, the [description](Self::description) is used
+    /// as fallback for the locales not provided.
+    pub fn localized_descriptions<I, K, V>(&mut self, iterator: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString
+    {
+        extend_localizations(&mut self.localized_descriptions, iterator);
+        self
+    }
+
     pub fn required_permissions(&mut self, permissions: Permissions) -> &mut Self {
         self.required_permissions = Some(permissions);
         self
@@ -296,51 +784,114 @@ impl<D, T, E> GroupParentBuilder<D, T, E> {
         self
     }
 
-    /// Sets this parent group as a [group](crate::group::ParentType::Group),
-    /// allowing to create subcommand groups inside of it.
+    /// Sets the default member permissions of this group, this is an alias of
+    /// [required_permissions](Self::required_permissions).
+    pub fn default_member_permissions(&mut self, permissions: Permissions) -> &mut Self {
+        self.required_permissions(permissions)
+    }
+
+    /// Sets whether the group can be used in direct messages, this is the opposite of
+    /// [only_guilds](Self::only_guilds).
+    pub fn dm_permission(&mut self, dm_permission: bool) -> &mut Self {
+        self.only_guilds(!dm_permission)
+    }
+
+    /// Sets the checks of this group, which are executed before the checks of the command used.
+    ///
+    /// If any of them returns `false` or an error, the command won't be executed.
+    pub fn checks(&mut self, checks: Vec<CheckHook<D, E>>) -> &mut Self {
+        self.checks = checks;
+        self
+    }
+
+    /// Sets the guilds this group will be registered in instead of registering it globally.
+    pub fn guilds<I>(&mut self, guilds: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Id<GuildMarker>>
+    {
+        self.guilds.extend(guilds);
+        self
+    }
+
+    /// Sets the error handler used by the commands of this group which don't have an error
+    /// handler, this includes the errors returned by the group checks.
+    pub fn error_handler(&mut self, hook: ErrorHandlerHook<D, E>) -> &mut Self {
+        self.error_handler = Some(hook);
+        self
+    }
+
+    /// Sets the subcommand executed when the group is invoked without a subcommand.
+    ///
+    /// Discord requires choosing a subcommand, so this only happens with malformed interactions,
+    /// which are otherwise treated as an unknown command.
+    ///
+    /// # Panics
+    ///
+    /// Building the group panics if it doesn't have a subcommand with the given name, subcommands
+    /// of subcommand groups can't be used.
+    pub fn default_subcommand(&mut self, name: &'static str) -> &mut Self {
+        self.default_subcommand = Some(name);
+        self
+    }
+
+    /// Adds a subcommand group to this parent group.
+    ///
+    /// Parent groups can contain both subcommand groups and subcommands at the same time, the
+    /// [kind](crate::group::ParentType) of the group is set depending on its children.
     pub fn group<F>(&mut self, fun: F) -> &mut Self
         where
             F: FnOnce(&mut CommandGroupBuilder<D, T, E>) -> &mut CommandGroupBuilder<D, T, E>,
     {
         let mut builder = CommandGroupBuilder::new();
         fun(&mut builder);
-        let built = builder.build();
+        self.add_group(builder)
+    }
 
-        if let ParentType::Group(map) = &mut self.kind {
-            assert!(!map.contains_key(built.name));
-            map.insert(built.name, built);
-        } else {
-            let mut map = CommandGroupMap::new();
-            map.insert(built.name, built);
-            self.kind = ParentType::Group(map);
-        }
+    /// Adds an already configured subcommand group to this parent group.
+    pub fn add_group(&mut self, group: CommandGroupBuilder<D, T, E>) -> &mut Self {
+        self.kind.add_group(group.build());
         self
     }
 
-    /// Sets this parent group as [simple](crate::group::ParentType::Simple), only allowing subcommands.
+    /// Adds a subcommand to this parent group.
     pub fn command(&mut self, fun: FnPointer<Command<D, T, E>>) -> &mut Self {
-        let command = fun();
+        self.add_command(fun())
+    }
+
+    /// Adds an already built subcommand to this parent group.
+    pub fn add_command(&mut self, mut command: Command<D, T, E>) -> &mut Self {
         assert!(matches!(command.kind, CommandType::ChatInput), "Only chat commands can be used inside groups");
-        if let ParentType::Simple(map) = &mut self.kind {
-            map.insert(command.name, command);
-        } else {
-            let mut map = CommandMap::new();
-            map.insert(command.name, command);
-            self.kind = ParentType::Simple(map);
-        }
+        command.order_arguments();
+        self.kind.add_command(command);
         self
     }
 
     /// Builds this parent group, returning a [group parent](crate::group::GroupParent).
     pub fn build(self) -> GroupParent<D, T, E> {
         assert!(self.name.is_some() && self.description.is_some());
+
+        if let Some(subcommand) = self.default_subcommand {
+            assert!(
+                self.kind.as_simple().is_some_and(|commands| commands.contains_key(subcommand)),
+                "Default subcommand [{}] not found in group [{}]",
+                subcommand,
+                self.name.unwrap()
+            );
+        }
+
         GroupParent {
             name: self.name.unwrap(),
             description: self.description.unwrap(),
+            localized_names: self.localized_names,
+            localized_descriptions: self.localized_descriptions,
             kind: self.kind,
             required_permissions: self.required_permissions,
             nsfw: self.nsfw,
-            only_guilds: self.only_guilds
+            only_guilds: self.only_guilds,
+            checks: self.checks,
+            error_handler: self.error_handler,
+            guilds: self.guilds,
+            default_subcommand: self.default_subcommand
         }
     }
 }
@@ -349,6 +900,8 @@ impl<D, T, E> GroupParentBuilder<D, T, E> {
 pub struct CommandGroupBuilder<D, T, E> {
     name: Option<&'static str>,
     description: Option<&'static str>,
+    localized_names: Option<HashMap<String, String>>,
+    localized_descriptions: Option<HashMap<String, String>>,
     subcommands: CommandMap<D, T, E>,
 }
 
@@ -365,31 +918,70 @@ impl<D, T, E> CommandGroupBuilder<D, T, E> {
         self
     }
 
+    /// Adds localized names to this group, the [name](Self::name) is used as fallback for the
+    /// locales not provided.
+    pub fn localized_names<I, K, V>(&mut self, iterator: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString
+    {
+        extend_localizations(&mut self.localized_names, iterator);
+        self
+    }
+
+    /// Adds localized descriptions to this group, the [description](Self::description) is used
+    /// as fallback for the locales not provided.
+    pub fn localized_descriptions<I, K, V>(&mut self, iterator: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString
+    {
+        extend_localizations(&mut self.localized_descriptions, iterator);
+        self
+    }
+
     /// Adds a command to this group.
     pub fn command(&mut self, fun: FnPointer<Command<D, T, E>>) -> &mut Self {
-        let command = fun();
+        self.add_command(fun())
+    }
+
+    /// Adds an already built command to this group.
+    pub fn add_command(&mut self, mut command: Command<D, T, E>) -> &mut Self {
         assert!(matches!(command.kind, CommandType::ChatInput), "Only chat commands can be used inside groups");
+        command.order_arguments();
         self.subcommands.insert(command.name, command);
         self
     }
 
     /// Builds the builder into a [group](crate::group::CommandGroup).
-    pub(crate) fn build(self) -> CommandGroup<D, T, E> {
+    pub fn build(self) -> CommandGroup<D, T, E> {
         assert!(self.name.is_some() && self.description.is_some());
 
         CommandGroup {
             name: self.name.unwrap(),
             description: self.description.unwrap(),
+            localized_names: self.localized_names,
+            localized_descriptions: self.localized_descriptions,
             subcommands: self.subcommands,
         }
     }
 
     /// Creates a new builder.
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             name: None,
             description: None,
+            localized_names: None,
+            localized_descriptions: None,
             subcommands: Default::default(),
         }
     }
 }
+
+impl<D, T, E> Default for CommandGroupBuilder<D, T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,34 @@
+//! Checks provided by the framework, ready to be used in commands and groups.
+
+use crate::{context::SlashContext, hook::{CheckHook, CheckResult}, BoxFuture};
+
+/// Returns a check which only passes if the command was invoked by one of the owners of the bot,
+/// set using [owners](crate::builder::FrameworkBuilder::owners) or
+/// [fetch_owners](crate::builder::FrameworkBuilder::fetch_owners).
+///
+/// The check returns `false` for anyone else, so to tell users they are not allowed to use the
+/// command, check for [CheckFailed](crate::command::ExecutionState::CheckFailed) in the
+/// execution result.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "Shuts down the bot"]
+/// async fn shutdown(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
+///     Ok(())
+/// }
+///
+/// // Only the owners are allowed to shut down the bot.
+/// let command = shutdown().checks(vec![vesper::checks::owners()]);
+/// ```
+pub fn owners<D, E: Send>() -> CheckHook<D, E> {
+    CheckHook(is_owner)
+}
+
+fn is_owner<'cx, 'data, D, E: Send>(ctx: &'cx mut SlashContext<'data, D>) -> BoxFuture<'cx, Result<CheckResult, E>> {
+    let owner = ctx.is_owner();
+    Box::pin(async move { Ok(owner.into()) })
+}
@@ -1,19 +1,44 @@
-use crate::localizations::{Localizations, LocalizationsProvider};
-use crate::prelude::{CreateCommandError, Framework};
+use crate::localizations::{validate_options, Localizations, LocalizationsProvider};
+use crate::prelude::{CreateCommandError, Framework, ValidationError};
 use crate::{
     argument::CommandArgument, context::SlashContext, twilight_exports::Permissions, BoxFuture, framework::ProcessResult,
 };
 use std::collections::HashMap;
-use tracing::{debug, info};
-use twilight_http::client::InteractionClient;
-use twilight_model::id::{marker::GuildMarker, Id};
-use crate::hook::{CheckHook, ErrorHandlerHook};
-use crate::twilight_exports::{Command as TwilightCommand, CommandType};
+use crate::hash::BuildNameHasher;
+use std::future::{poll_fn, Future, IntoFuture};
+use std::ops::Deref;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Poll;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+use tracing::{debug, info, info_span, warn, Instrument};
+use twilight_http::{client::InteractionClient, response::{marker::EmptyBody, ResponseFuture}};
+use twilight_validate::command::OPTIONS_LIMIT;
+use twilight_model::channel::message::MessageFlags;
+use twilight_model::id::{marker::{GuildMarker, InteractionMarker}, Id};
+use crate::cooldown::Cooldown;
+use crate::group::GroupParent;
+use crate::integration::{self, IntegrationType, InteractionContext};
+use crate::sync::CommandDefinition;
+use crate::hook::{CheckHook, CheckResult, ErrorHandlerHook};
+use crate::iter::FlattenedOptions;
+use crate::twilight_exports::{
+    Command as TwilightCommand, CommandType, Interaction, InteractionData, InteractionResponse,
+    InteractionResponseData, InteractionResponseType
+};
 
 /// A pointer to a command function.
 pub(crate) type CommandFn<D, T, E> = for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>) -> BoxFuture<'cx, Result<T, E>>;
 /// A map of [commands](self::Command).
-pub type CommandMap<D, T, E> = HashMap<&'static str, Command<D, T, E>>;
+///
+/// The map uses the [name hasher](crate::hash::NameHasher) instead of the default one, so it must
+/// be created using `CommandMap::default()`, since `HashMap::new()` is only available for maps
+/// using the default hasher.
+pub type CommandMap<D, T, E> = HashMap<&'static str, Command<D, T, E>, BuildNameHasher>;
+/// A map of the [commands](self::Command) registered at runtime, by type and name.
+pub(crate) type RuntimeCommandMap<D, T, E> = HashMap<(CommandType, &'static str), Arc<Command<D, T, E>>>;
 
 #[doc(hidden)]
 #[macro_export]
@@ -25,20 +50,44 @@ macro_rules! if_some {
     };
 }
 
+/// A reference to a command, which can be either provided to the framework builder or
+/// registered at runtime.
+pub(crate) enum CommandRef<'a, D, T, E> {
+    Borrowed(&'a Command<D, T, E>),
+    Shared(Arc<Command<D, T, E>>)
+}
+
+impl<'a, D, T, E> Deref for CommandRef<'a, D, T, E> {
+    type Target = Command<D, T, E>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(command) => command,
+            Self::Shared(command) => command
+        }
+    }
+}
+
 /// Information about the execution state of a command.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug)]
 pub enum ExecutionState {
     /// A check had an error.
     CheckErrored,
-    /// A check returned `false` and the command didn't execute.
+    /// A check failed and the command didn't execute, the reason given by the check, if any, is
+    /// available at [ExecutionResult::reason].
     CheckFailed,
     /// The command finished executing without errors.
     CommandFinished,
     /// The error handler raised an error. 
     CommandErrored,
     /// The `before` hook returned `false` and the command didn't execute.
-    BeforeHookFailed
+    BeforeHookFailed,
+    /// The command is on cooldown and didn't execute, contains the remaining time until the
+    /// command can be used again.
+    OnCooldown(Duration),
+    /// The locale of the user is not allowed to use the command, so it didn't execute.
+    LocaleRestricted
 }
 
 /// The location of the output of the command.
@@ -49,9 +98,12 @@ pub enum OutputLocation<T, E> {
     /// The output has not been taken by any hook.
     Present(Result<T, E>),
     /// The output has been forwarded to the `after` hook.
+    #[deprecated(note = "The `after` hooks borrow the output, so it stays `Present`")]
     TakenByAfterHook,
     /// The output has been taken by the `error_handler` hook.
-    TakenByErrorHandler
+    TakenByErrorHandler,
+    /// The output has been forwarded to the `execution` hook.
+    TakenByExecutionHook
 }
 
 /// Information about the command execution and it's output.
@@ -59,7 +111,14 @@ pub struct ExecutionResult<T, E> {
     /// The execution state of the command.
     pub state: ExecutionState,
     /// The output of the command.
-    pub output: OutputLocation<T, E>
+    pub output: OutputLocation<T, E>,
+    /// The time the command function took to execute, zero if the command was not executed.
+    pub elapsed: Duration,
+    /// Whether the interaction was acknowledged while processing the command, this is also the
+    /// case when the `before` hook denies the execution after sending its own response.
+    pub acknowledged: bool,
+    /// The reason given by the check which prevented the command from executing, if any.
+    pub reason: Option<String>
 }
 
 impl<T, E> From<ExecutionResult<T, E>> for ProcessResult<T, E> {
@@ -86,7 +145,23 @@ pub struct Command<D, T, E> {
     pub nsfw: bool,
     pub only_guilds: bool,
     pub checks: Vec<CheckHook<D, E>>,
-    pub error_handler: Option<ErrorHandlerHook<D, E>>
+    pub error_handler: Option<ErrorHandlerHook<D, E>>,
+    /// The guilds this command is registered in, if empty, the command is registered globally.
+    pub guilds: Vec<Id<GuildMarker>>,
+    /// The cooldown applied to this command, if any.
+    pub cooldown: Option<Cooldown>,
+    /// The locales allowed to use this command, if empty, the command can be used from any locale.
+    pub allowed_locales: Vec<String>,
+    /// Whether the responses of this command are ephemeral by default.
+    pub ephemeral: bool,
+    /// The time after which the interaction is deferred if the command didn't acknowledge it.
+    pub auto_defer: Option<Duration>,
+    /// The installation contexts this command is available in, if empty, discord's default is used.
+    pub integration_types: Vec<IntegrationType>,
+    /// The contexts this command can be used in, if empty, discord's default is used.
+    pub contexts: Vec<InteractionContext>,
+    /// Sets of optional arguments where at least one argument of each set must be provided.
+    pub required_one_of: Vec<Vec<&'static str>>
 }
 
 impl<D, T, E> Command<D, T, E> {
@@ -104,7 +179,15 @@ impl<D, T, E> Command<D, T, E> {
             nsfw: false,
             only_guilds: false,
             checks: Default::default(),
-            error_handler: None
+            error_handler: None,
+            guilds: Vec::new(),
+            cooldown: None,
+            allowed_locales: Vec::new(),
+            ephemeral: false,
+            auto_defer: None,
+            integration_types: Vec::new(),
+            contexts: Vec::new(),
+            required_one_of: Vec::new()
         }
     }
 
@@ -141,11 +224,161 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
+    /// Sets the cooldown of this command, which is started once the checks pass.
+    pub fn cooldown(mut self, cooldown: Cooldown) -> Self {
+        self.cooldown = Some(cooldown);
+        self
+    }
+
+    /// Restricts the command to the given locales, users with any other locale won't be able to
+    /// execute it, finishing its execution with
+    /// [LocaleRestricted](ExecutionState::LocaleRestricted).
+    pub fn restrict_locales<I, L>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: ToString
+    {
+        self.allowed_locales.extend(locales.into_iter().map(|locale| locale.to_string()));
+        self
+    }
+
+    /// Requires at least one of the given arguments to be provided, referred by the name they are
+    /// registered with. If none of them is provided, the command doesn't execute and finishes
+    /// with [CheckFailed](ExecutionState::CheckFailed), giving the reason at
+    /// [ExecutionResult::reason].
+    ///
+    /// This is useful for edit-style commands, where all the arguments are optional but at least
+    /// one field must be changed. Calling this multiple times requires one argument of each set.
+    pub fn require_one_of<I>(mut self, arguments: I) -> Self
+    where
+        I: IntoIterator<Item = &'static str>
+    {
+        self.required_one_of.push(arguments.into_iter().collect());
+        self
+    }
+
+    /// Returns the reason the command can't execute if none of the arguments of any of the
+    /// [required sets](Self::require_one_of) was provided in the given interaction.
+    fn missing_one_of(&self, interaction: &Interaction) -> Option<String> {
+        let Some(InteractionData::ApplicationCommand(data)) = &interaction.data else {
+            return None;
+        };
+
+        // Variadic arguments are provided as one option per value, named `<name>_<index>`.
+        let provided = |name: &str| FlattenedOptions::new(data).any(|(_, option)| {
+            option.name == name || option.name.strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('_'))
+                .is_some_and(|index| index.parse::<usize>().is_ok())
+        });
+
+        let missing = self.required_one_of.iter()
+            .find(|arguments| !arguments.iter().any(|name| provided(name)))?;
+
+        Some(format!(
+            "At least one of the arguments {} must be provided",
+            missing.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", ")
+        ))
+    }
+
+    /// Moves the required arguments before the optional ones, as discord rejects commands with
+    /// optional options placed before required ones. The relative order of the arguments is
+    /// otherwise preserved.
+    ///
+    /// Required variadic arguments are placed after the rest of the required ones, since only
+    /// their first option is required.
+    pub(crate) fn order_arguments(&mut self) {
+        let position = |argument: &CommandArgument<D, T, E>| match (argument.required, argument.variadic) {
+            (true, None) => 0,
+            (true, Some(_)) => 1,
+            (false, _) => 2
+        };
+
+        if self.arguments.windows(2).any(|pair| position(&pair[0]) > position(&pair[1])) {
+            debug!("Command [{}] has optional arguments before required ones, reordering them", self.name);
+            self.arguments.sort_by_key(position);
+        }
+    }
+
+    /// Validates the arguments of this command, where `path` is the full name of the command,
+    /// adding the problems found to the given errors.
+    ///
+    /// The arguments are checked on the options they are registered as, since variadic arguments
+    /// register one option per value, named `<name>_1`, `<name>_2`..., and only the first option
+    /// of a required variadic argument is required.
+    pub(crate) fn validate_arguments(&self, f: &Framework<D, T, E>, path: &str, errors: &mut Vec<ValidationError>) {
+        let mut optional_found = false;
+        let mut registered = Vec::<String>::new();
+
+        for argument in &self.arguments {
+            let options = argument.as_options(f, self);
+
+            // Report each argument once, even if several of its options are repeated.
+            if let Some(option) = options.iter().find(|option| registered.contains(&option.name)) {
+                errors.push(ValidationError::DuplicateArgument {
+                    command: path.to_string(),
+                    argument: option.name.clone()
+                });
+            }
+
+            for option in options {
+                let required = option.required.unwrap_or(false);
+
+                if required && optional_found {
+                    errors.push(ValidationError::RequiredAfterOptional {
+                        command: path.to_string(),
+                        argument: argument.name.to_string()
+                    });
+                }
+
+                if let Err(error) = ValidationError::check_argument_name(path, &option.name) {
+                    errors.push(error);
+                }
+
+                optional_found |= !required;
+                registered.push(option.name);
+            }
+        }
+
+        for name in self.required_one_of.iter().flatten() {
+            let reason = match self.arguments.iter().find(|argument| argument.name == *name) {
+                None => "does not exist",
+                Some(argument) if argument.required => "is already required",
+                Some(_) => continue
+            };
+
+            errors.push(ValidationError::InvalidRequiredArgument {
+                command: path.to_string(),
+                argument: name.to_string(),
+                reason
+            });
+        }
+
+        if registered.len() > OPTIONS_LIMIT {
+            errors.push(ValidationError::TooManyOptions {
+                command: path.to_string(),
+                amount: registered.len()
+            });
+        }
+    }
+
+    /// Returns whether the given locale is allowed to use this command.
+    pub fn is_locale_allowed(&self, locale: Option<&str>) -> bool {
+        self.allowed_locales.is_empty()
+            || locale.is_some_and(|locale| self.allowed_locales.iter().any(|allowed| allowed == locale))
+    }
+
     pub fn required_permissions(mut self, permissions: Permissions) -> Self {
         self.required_permissions = Some(permissions);
         self
     }
 
+    /// Sets the permissions a member needs by default to see and use this command, server
+    /// administrators can modify them later from discord. This is an alias of
+    /// [required_permissions](Self::required_permissions).
+    pub fn default_member_permissions(self, permissions: Permissions) -> Self {
+        self.required_permissions(permissions)
+    }
+
     pub fn nsfw(mut self, nsfw: bool) -> Self {
         self.nsfw = nsfw;
         self
@@ -156,6 +389,77 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
+    /// Sets whether the responses of this command are ephemeral by default, making both
+    /// [responses](crate::context::SlashContext::respond) and
+    /// [deferred responses](crate::context::SlashContext::defer) only visible to the user who
+    /// invoked the command.
+    ///
+    /// Responses can still be made public using
+    /// [set_ephemeral](crate::response::ResponseBuilder::set_ephemeral).
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    /// Defers the interaction automatically if the command function doesn't acknowledge it
+    /// within the given duration, so slow commands don't fail before responding.
+    ///
+    /// Once deferred, [respond](SlashContext::respond) edits the deferred response. Discord fails
+    /// interactions not acknowledged within three seconds, so the duration should be shorter.
+    pub fn auto_defer(mut self, after: Duration) -> Self {
+        self.auto_defer = Some(after);
+        self
+    }
+
+    /// Sets whether the command can be used in direct messages, this is the opposite of
+    /// [only_guilds](Self::only_guilds).
+    ///
+    /// This only affects globally registered commands, as guild commands are never available in
+    /// direct messages.
+    pub fn dm_permission(self, dm_permission: bool) -> Self {
+        self.only_guilds(!dm_permission)
+    }
+
+    /// Sets the installation contexts this command is available in, this allows the command to be
+    /// used when the application is installed to a user instead of a guild.
+    pub fn integration_types<I>(mut self, integration_types: I) -> Self
+    where
+        I: IntoIterator<Item = IntegrationType>
+    {
+        self.integration_types.extend(integration_types);
+        self
+    }
+
+    /// Sets the contexts this command can be used in.
+    ///
+    /// The integration types and contexts of registered commands aren't compared when
+    /// [syncing](crate::framework::Framework::sync_commands), so changing them alone isn't detected.
+    pub fn contexts<I>(mut self, contexts: I) -> Self
+    where
+        I: IntoIterator<Item = InteractionContext>
+    {
+        self.contexts.extend(contexts);
+        self
+    }
+
+    /// Sets the guilds this command will be registered in instead of registering it globally.
+    pub fn guilds<I>(mut self, guilds: I) -> Self
+    where
+        I: IntoIterator<Item = Id<GuildMarker>>
+    {
+        self.guilds.extend(guilds);
+        self
+    }
+
+    /// Returns whether the command should be registered in the given guild, or globally if `None`
+    /// is provided.
+    pub fn registered_in(&self, guild: Option<Id<GuildMarker>>) -> bool {
+        match guild {
+            Some(id) => self.guilds.is_empty() || self.guilds.contains(&id),
+            None => self.guilds.is_empty()
+        }
+    }
+
     pub fn localized_names<I, K, V>(mut self, iterator: I) -> Self 
     where
         I: IntoIterator<Item = (K, V)>,
@@ -188,16 +492,22 @@ impl<D, T, E> Command<D, T, E> {
         self
     }
 
-    pub async fn run_checks<'cx, 'data: 'cx>(&self, context: &'cx mut SlashContext<'data, D>) -> Result<bool, E> {
+    pub async fn run_checks<'cx, 'data: 'cx>(&self, context: &'cx mut SlashContext<'data, D>) -> Result<CheckResult, E> {
+        if context.bypass_checks {
+            debug!("Command [{}] checks bypassed", self.name);
+            return Ok(CheckResult::Passed);
+        }
+
         debug!("Running command [{}] checks", self.name);
         for check in &self.checks {
-            if !(check.0)(context).await? {
-                debug!("Command [{}] check returned false", self.name);
-                return Ok(false);
+            let result = (check.0)(context).await?;
+            if !result.is_passed() {
+                debug!("Command [{}] check failed", self.name);
+                return Ok(result);
             }
         }
         debug!("All command [{}] checks passed", self.name);
-        Ok(true)
+        Ok(CheckResult::Passed)
     }
 
     async fn create_chat_command(
@@ -207,10 +517,14 @@ impl<D, T, E> Command<D, T, E> {
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        ValidationError::check_description(self.name, self.description)?;
+
         let options = self.arguments.iter()
-            .map(|a| a.as_option(framework, self))
+            .flat_map(|a| a.as_options(framework, self))
             .collect::<Vec<_>>();
 
+        validate_options(&options)?;
+
         let name_localizations = self.localized_names.get_localizations(framework, &self);
         let description_localizations = self.localized_descriptions.get_localizations(framework, &self);
 
@@ -244,16 +558,21 @@ impl<D, T, E> Command<D, T, E> {
 
     async fn create_user_command(
         &self,
+        framework: &Framework<D, T, E>,
         http: &InteractionClient<'_>,
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        // Context menu commands don't have a description, so only names can be localized.
+        let name_localizations = self.localized_names.get_localizations(framework, self);
+
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
                 .user(self.name)?
                 .nsfw(self.nsfw);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         } else {
@@ -263,6 +582,7 @@ impl<D, T, E> Command<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         };
@@ -272,16 +592,21 @@ impl<D, T, E> Command<D, T, E> {
 
     async fn create_message_command(
         &self,
+        framework: &Framework<D, T, E>,
         http: &InteractionClient<'_>,
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        // Context menu commands don't have a description, so only names can be localized.
+        let name_localizations = self.localized_names.get_localizations(framework, self);
+
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
                 .message(self.name)?
                 .nsfw(self.nsfw);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         } else {
@@ -291,6 +616,7 @@ impl<D, T, E> Command<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            if_some!(&name_localizations, |n| command = command.name_localizations(n)?);
 
             command.await?.model().await?
         };
@@ -298,6 +624,24 @@ impl<D, T, E> Command<D, T, E> {
         Ok(model)
     }
 
+    /// Registers the command with its integration types and contexts, which twilight doesn't
+    /// support yet.
+    async fn create_with_contexts(
+        &self,
+        framework: &Framework<D, T, E>,
+        guild: Option<Id<GuildMarker>>
+    ) -> Result<TwilightCommand, CreateCommandError>
+    {
+        if self.kind == CommandType::ChatInput {
+            ValidationError::check_description(self.name, self.description)?;
+        }
+
+        let definition = CommandDefinition::from_command(framework, self);
+        validate_options(definition.options())?;
+
+        integration::create_command(framework, definition, guild, &self.integration_types, &self.contexts).await
+    }
+
     pub async fn create(
         &self,
         framework: &Framework<D, T, E>,
@@ -305,24 +649,115 @@ impl<D, T, E> Command<D, T, E> {
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        if !self.integration_types.is_empty() || !self.contexts.is_empty() {
+            return self.create_with_contexts(framework, guild).await;
+        }
+
         match self.kind {
             CommandType::ChatInput => self.create_chat_command(framework, http, guild).await,
-            CommandType::Message => self.create_message_command(http, guild).await,
-            CommandType::User => self.create_user_command(http, guild).await,
+            CommandType::Message => self.create_message_command(framework, http, guild).await,
+            CommandType::User => self.create_user_command(framework, http, guild).await,
             _ => panic!("Invalid command type")
         }
     }
 
     pub async fn execute<'cx, 'data: 'cx>(&self, context: &'cx mut SlashContext<'data, D>) -> ExecutionResult<T, E> {
+        self.execute_in(context, None, None).await
+    }
+
+    /// Executes the command as part of the given group, running the group checks before the
+    /// command ones and using the group error handler if the command doesn't have one, falling
+    /// back to the given error handler if neither of them has one.
+    ///
+    /// The checks and the command function run inside a `command` span, containing the name of
+    /// the command and its group, and the ids of the user and guild it was used by.
+    pub(crate) async fn execute_in<'cx, 'data: 'cx>(
+        &self,
+        context: &'cx mut SlashContext<'data, D>,
+        group: Option<&GroupParent<D, T, E>>,
+        fallback: Option<&ErrorHandlerHook<D, E>>
+    ) -> ExecutionResult<T, E> {
+        let span = info_span!(
+            "command",
+            name = self.name,
+            group = group.map(|group| group.name),
+            user_id = context.interaction.author_id().map(|id| id.get()),
+            guild_id = context.interaction.guild_id.map(|id| id.get())
+        );
+
+        self.run(context, group, fallback).instrument(span).await
+    }
+
+    async fn run<'cx, 'data: 'cx>(
+        &self,
+        context: &'cx mut SlashContext<'data, D>,
+        group: Option<&GroupParent<D, T, E>>,
+        fallback: Option<&ErrorHandlerHook<D, E>>
+    ) -> ExecutionResult<T, E> {
         let state;
         let location;
+        let mut elapsed = Duration::ZERO;
+        let mut reason = None;
+        let error_handler = self.error_handler.as_ref()
+            .or_else(|| group.and_then(|group| group.error_handler.as_ref()))
+            .or(fallback);
+
+        if !self.is_locale_allowed(context.interaction.locale.as_deref()) {
+            debug!("Command [{}] is not available in the locale of the user", self.name);
+            return ExecutionResult {
+                state: ExecutionState::LocaleRestricted,
+                output: OutputLocation::NotExecuted,
+                elapsed: Duration::ZERO,
+                acknowledged: false,
+                reason: None
+            };
+        }
+
+        if let Some(reason) = self.missing_one_of(&context.interaction) {
+            debug!("Command [{}] was used without any of its required arguments", self.name);
+            return ExecutionResult {
+                state: ExecutionState::CheckFailed,
+                output: OutputLocation::NotExecuted,
+                elapsed: Duration::ZERO,
+                acknowledged: false,
+                reason: Some(reason)
+            };
+        }
+
+        let checks = match group {
+            Some(group) => group.run_checks(context).await,
+            None => Ok(CheckResult::Passed)
+        };
+
+        let checks = match checks {
+            Ok(CheckResult::Passed) => self.run_checks(context).await,
+            other => other
+        };
+
+        let cooldown = match (&checks, &self.cooldown) {
+            (Ok(CheckResult::Passed), Some(cooldown)) => cooldown.trigger(&context.interaction).err(),
+            _ => None
+        };
 
-        match self.run_checks(context).await {
-            Ok(true) => {
+        match (checks, cooldown) {
+            (Ok(CheckResult::Passed), Some(remaining)) => {
+                debug!("Command [{}] is on cooldown", self.name);
+                state = ExecutionState::OnCooldown(remaining);
+                location = OutputLocation::NotExecuted;
+            },
+            (Ok(CheckResult::Passed), None) => {
                 debug!("Executing command [{}]", self.name);
-                let output = (self.fun)(context).await;
+                let started = Instant::now();
+                let output = match self.auto_defer {
+                    Some(after) => {
+                        let defer = AutoDefer::new(context, after);
+                        defer.run(self.name, (self.fun)(context)).await
+                    },
+                    None => (self.fun)(context).await
+                };
+                elapsed = started.elapsed();
 
-                match (&self.error_handler, output) {
+                match (error_handler, output) {
                     (Some(hook), Err(why)) => {
                         info!("Command [{}] raised an error, using established error handler", self.name);
                         state = ExecutionState::CommandErrored;
@@ -342,10 +777,10 @@ impl<D, T, E> Command<D, T, E> {
                     }
                 };
             },
-            Err(why) => {
+            (Err(why), _) => {
                 state = ExecutionState::CheckErrored;
                 // If the command has an error handler, execute it, if not, discard the error.
-                if let Some(hook) = &self.error_handler {
+                if let Some(hook) = error_handler {
                     info!("Command [{}] check raised an error, using established error handler", self.name);
                     (hook.0)(context, why).await;
                     location = OutputLocation::TakenByErrorHandler;
@@ -354,15 +789,98 @@ impl<D, T, E> Command<D, T, E> {
                     location = OutputLocation::Present(Err(why));
                 }
             },
-            _ => {
+            (Ok(CheckResult::Failed(why)), _) => {
                 state = ExecutionState::CheckFailed;
                 location = OutputLocation::NotExecuted;
+                reason = why;
             }
         }
 
         ExecutionResult {
             state,
-            output: location
+            output: location,
+            elapsed,
+            acknowledged: false,
+            reason
         }
     }
 }
+
+/// Defers an interaction once a command has been executing for some time without acknowledging
+/// it.
+struct AutoDefer<'a> {
+    client: InteractionClient<'a>,
+    interaction_id: Id<InteractionMarker>,
+    token: String,
+    flags: Option<MessageFlags>,
+    acknowledged: Arc<AtomicBool>,
+    after: Duration
+}
+
+impl<'a> AutoDefer<'a> {
+    fn new<D>(context: &SlashContext<'a, D>, after: Duration) -> Self {
+        Self {
+            client: context.http_client.interaction(context.application_id),
+            interaction_id: context.interaction.id,
+            token: context.interaction.token.clone(),
+            flags: context.ephemeral.then_some(MessageFlags::EPHEMERAL),
+            acknowledged: Arc::clone(&context.acknowledged),
+            after
+        }
+    }
+
+    /// Runs the given command, deferring the interaction if it isn't acknowledged in time.
+    ///
+    /// The command is not polled while the interaction is being deferred, so its responses are
+    /// always sent after the deferred one.
+    async fn run<F: Future>(self, name: &str, command: F) -> F::Output {
+        let mut command = pin!(command);
+        let mut timer = Some(Box::pin(sleep(self.after)));
+        let mut request: Option<ResponseFuture<EmptyBody>> = None;
+
+        poll_fn(|cx| {
+            if let Some(sleep) = timer.as_mut() {
+                if sleep.as_mut().poll(cx).is_ready() {
+                    timer = None;
+
+                    if !self.acknowledged.swap(true, Ordering::AcqRel) {
+                        debug!("Command [{}] didn't acknowledge the interaction in time, deferring it", name);
+                        request = Some(self.request());
+                    }
+                }
+            }
+
+            if let Some(future) = request.as_mut() {
+                match Pin::new(future).poll(cx) {
+                    Poll::Ready(result) => {
+                        request = None;
+
+                        if let Err(why) = result {
+                            warn!("Failed to defer command [{}]: {}", name, why);
+                            self.acknowledged.store(false, Ordering::Release);
+                        }
+                    },
+                    Poll::Pending => return Poll::Pending
+                }
+            }
+
+            command.as_mut().poll(cx)
+        }).await
+    }
+
+    fn request(&self) -> ResponseFuture<EmptyBody> {
+        self.client
+            .create_response(
+                self.interaction_id,
+                &self.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::DeferredChannelMessageWithSource,
+                    data: self.flags.map(|flags| InteractionResponseData {
+                        flags: Some(flags),
+                        ..Default::default()
+                    })
+                }
+            )
+            .into_future()
+    }
+}
@@ -0,0 +1,246 @@
+//! Builders used to create message components, such as buttons and select menus.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use vesper::prelude::*;
+//! use vesper::components::{ActionRowBuilder, ButtonBuilder, SelectMenuBuilder};
+//! use vesper::twilight_exports::ButtonStyle;
+//!
+//! #[command]
+//! #[description = "Shows some components"]
+//! async fn components(ctx: &SlashContext<()>) -> DefaultCommandResult {
+//!     ctx.respond()
+//!         .content("Pick an option")
+//!         .action_row(ActionRowBuilder::new()
+//!             .button(ButtonBuilder::new(ButtonStyle::Primary, "yes").label("Yes"))
+//!             .button(ButtonBuilder::new(ButtonStyle::Danger, "no").label("No")))
+//!         .action_row(ActionRowBuilder::new()
+//!             .select_menu(SelectMenuBuilder::new("color")
+//!                 .option("Red", "red")
+//!                 .option("Blue", "blue")))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use thiserror::Error;
+use twilight_validate::component::{action_row, button, select_menu, ComponentValidationError, COMPONENT_COUNT};
+use crate::twilight_exports::{ActionRow, Button, ButtonStyle, Component, ReactionType, SelectMenu, SelectMenuOption};
+
+/// Errors returned when building invalid components.
+#[derive(Debug, Error)]
+pub enum ComponentError {
+    /// A button had neither a label nor an emoji.
+    #[error("Buttons must have a label or an emoji")]
+    MissingLabel,
+    /// A select menu was placed in a row along other components.
+    #[error("Select menus must be the only component of their row")]
+    SelectMenuNotAlone,
+    /// More than 5 action rows were provided.
+    #[error("A message can't have more than {COMPONENT_COUNT} action rows")]
+    TooManyRows,
+    /// A component did not pass discord validation.
+    #[error(transparent)]
+    Validation(#[from] ComponentValidationError)
+}
+
+/// A builder used to create a [button](Button).
+#[derive(Debug, Clone)]
+pub struct ButtonBuilder {
+    button: Button
+}
+
+impl ButtonBuilder {
+    /// Creates a new button with the given style and custom id.
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>) -> Self {
+        Self {
+            button: Button {
+                custom_id: Some(custom_id.into()),
+                disabled: false,
+                emoji: None,
+                label: None,
+                style,
+                url: None
+            }
+        }
+    }
+
+    /// Creates a new button linking to the given url.
+    pub fn link(url: impl Into<String>) -> Self {
+        Self {
+            button: Button {
+                custom_id: None,
+                disabled: false,
+                emoji: None,
+                label: None,
+                style: ButtonStyle::Link,
+                url: Some(url.into())
+            }
+        }
+    }
+
+    /// Sets the label of the button.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.button.label = Some(label.into());
+        self
+    }
+
+    /// Sets the emoji of the button.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.button.emoji = Some(emoji);
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.button.disabled = disabled;
+        self
+    }
+
+    /// Validates the button, returning it as a component.
+    pub fn build(self) -> Result<Component, ComponentError> {
+        if self.button.label.is_none() && self.button.emoji.is_none() {
+            return Err(ComponentError::MissingLabel);
+        }
+
+        button(&self.button)?;
+        Ok(Component::Button(self.button))
+    }
+}
+
+/// A builder used to create a [select menu](SelectMenu).
+#[derive(Debug, Clone)]
+pub struct SelectMenuBuilder {
+    select_menu: SelectMenu
+}
+
+impl SelectMenuBuilder {
+    /// Creates a new select menu with the given custom id.
+    pub fn new(custom_id: impl Into<String>) -> Self {
+        Self {
+            select_menu: SelectMenu {
+                custom_id: custom_id.into(),
+                disabled: false,
+                max_values: None,
+                min_values: None,
+                options: Vec::new(),
+                placeholder: None
+            }
+        }
+    }
+
+    /// Adds an option with the given label and value to the select menu.
+    pub fn option(self, label: impl Into<String>, value: impl Into<String>) -> Self {
+        self.add_option(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into()
+        })
+    }
+
+    /// Adds the given option to the select menu.
+    pub fn add_option(mut self, option: SelectMenuOption) -> Self {
+        self.select_menu.options.push(option);
+        self
+    }
+
+    /// Sets the placeholder shown when no option is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.select_menu.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the minimum amount of options that must be selected.
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.select_menu.min_values = Some(min_values);
+        self
+    }
+
+    /// Sets the maximum amount of options that can be selected.
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.select_menu.max_values = Some(max_values);
+        self
+    }
+
+    /// Sets whether the select menu is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.select_menu.disabled = disabled;
+        self
+    }
+
+    /// Validates the select menu, returning it as a component.
+    pub fn build(self) -> Result<Component, ComponentError> {
+        select_menu(&self.select_menu)?;
+        Ok(Component::SelectMenu(self.select_menu))
+    }
+}
+
+/// An item of an [action row](ActionRowBuilder).
+#[derive(Debug, Clone)]
+enum RowItem {
+    Button(ButtonBuilder),
+    SelectMenu(SelectMenuBuilder)
+}
+
+/// A builder used to create an [action row](ActionRow), which can contain up to 5 buttons or a
+/// single select menu.
+#[derive(Debug, Clone, Default)]
+pub struct ActionRowBuilder {
+    items: Vec<RowItem>
+}
+
+impl ActionRowBuilder {
+    /// Creates a new, empty, action row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a button to the row.
+    pub fn button(mut self, button: ButtonBuilder) -> Self {
+        self.items.push(RowItem::Button(button));
+        self
+    }
+
+    /// Adds a select menu to the row.
+    pub fn select_menu(mut self, select_menu: SelectMenuBuilder) -> Self {
+        self.items.push(RowItem::SelectMenu(select_menu));
+        self
+    }
+
+    /// Validates the row and its components, returning it as a component.
+    pub fn build(self) -> Result<Component, ComponentError> {
+        let has_select_menu = self.items.iter().any(|item| matches!(item, RowItem::SelectMenu(_)));
+        if has_select_menu && self.items.len() > 1 {
+            return Err(ComponentError::SelectMenuNotAlone);
+        }
+
+        let row = ActionRow {
+            components: self.items.into_iter()
+                .map(|item| match item {
+                    RowItem::Button(button) => button.build(),
+                    RowItem::SelectMenu(select_menu) => select_menu.build()
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        action_row(&row)?;
+        Ok(Component::ActionRow(row))
+    }
+}
+
+/// Validates and builds the given action rows, returning an error if there are more than 5.
+pub fn build_rows(rows: impl IntoIterator<Item = ActionRowBuilder>) -> Result<Vec<Component>, ComponentError> {
+    let rows = rows.into_iter()
+        .map(ActionRowBuilder::build)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if rows.len() > COMPONENT_COUNT {
+        return Err(ComponentError::TooManyRows);
+    }
+
+    Ok(rows)
+}
@@ -1,13 +1,41 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::{poll_fn, Future, IntoFuture};
+use std::pin::{pin, Pin};
+use std::task::Poll;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use parking_lot::Mutex;
+use tokio::time::sleep;
+use twilight_http::response::ResponseFuture;
+use tokio::sync::oneshot::error::RecvError;
 use twilight_model::channel::message::MessageFlags;
 use crate::{
     builder::WrappedClient,
+    components::{ActionRowBuilder, ButtonBuilder, ComponentError},
+    error::{ConfirmError, FetchMessageError},
+    extensions::Extensions,
+    parsers::MessageLink,
     twilight_exports::*,
-    wait::{InteractionWaiter, WaiterWaker}
+    wait::{InteractionStream, InteractionWaiter, WaiterWaker}
 };
 
-use crate::modal::{Modal, WaitModal};
-use crate::wait::new_pair;
+use crate::iter::DataIterator;
+use crate::modal::{Modal, ModalBuilder, ModalError, WaitModal};
+use crate::parse::{Parse, ParseError, ParseTarget};
+use crate::response::{ResponseBuilder, ResponseError};
+use crate::wait::{new_pair, new_stream_pair};
+use tracing::warn;
+
+/// The maximum amount of choices discord allows in an autocomplete response.
+const AUTOCOMPLETE_CHOICES_LIMIT: usize = 25;
+
+/// The time an interaction token stays valid after the interaction is created, once it expires,
+/// the response and followups can no longer be created nor edited.
+pub const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// The first second of 2015, in milliseconds since the unix epoch, used by discord snowflakes.
+pub(crate) const DISCORD_EPOCH: u64 = 1_420_070_400_000;
 
 /// The value the user is providing to the argument.
 #[derive(Debug, Clone)]
@@ -26,6 +54,8 @@ pub struct AutocompleteContext<'a, D> {
     pub user_input: Focused,
     /// The interaction itself.
     pub interaction: &'a mut Interaction,
+    /// Whether the autocomplete interaction has already been responded.
+    pub(crate) responded: &'a AtomicBool,
 }
 
 impl<'a, D> AutocompleteContext<'a, D> {
@@ -34,12 +64,14 @@ impl<'a, D> AutocompleteContext<'a, D> {
         data: &'a D,
         user_input: Focused,
         interaction: &'a mut Interaction,
+        responded: &'a AtomicBool,
     ) -> Self {
         Self {
             http_client,
             data,
             user_input,
             interaction,
+            responded,
         }
     }
 
@@ -47,6 +79,118 @@ impl<'a, D> AutocompleteContext<'a, D> {
     pub fn http_client(&self) -> &Client {
         self.http_client.inner()
     }
+
+    /// Parses the value of another argument of the command being autocompleted, returning `None`
+    /// if the user has not filled it yet.
+    ///
+    /// Since the user may still be filling the command, the argument may fail to parse, so
+    /// this returns the parse result instead of failing the whole autocomplete function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    /// use vesper::twilight_exports::InteractionResponseData;
+    ///
+    /// #[autocomplete]
+    /// async fn autocomplete_city(mut ctx: AutocompleteContext<()>) -> Option<InteractionResponseData> {
+    ///     let country = ctx.argument::<String>("country").await?.ok()?;
+    ///
+    ///     // Suggest cities of the selected country...
+    ///     None
+    /// }
+    /// ```
+    pub async fn argument<T>(&mut self, name: &str) -> Option<Result<T, ParseError>>
+    where
+        T: Parse<D>,
+        D: Send + Sync
+    {
+        let Some(InteractionData::ApplicationCommand(data)) = self.interaction.data.as_mut() else {
+            return None;
+        };
+        let data = &mut **data;
+
+        let option = DataIterator::<D>::get_data(&data.options)
+            .into_iter()
+            .find(|option| option.name == name)?;
+
+        // The focused option is the one being autocompleted, which is available as the user input.
+        if let CommandOptionValue::Focused(..) = option.value {
+            return None;
+        }
+
+        Some(T::parse(self.http_client, self.data, Some(&option.value), data.resolved.as_mut())
+            .await
+            .map_err(|mut why| {
+                why.set_argument_name(name);
+                why
+            }))
+    }
+
+    /// Responds to the autocomplete interaction with the given choices.
+    ///
+    /// Discord only allows up to 25 choices, so any choice over that limit is discarded. When
+    /// using this method, the value returned by the autocomplete function is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    /// use vesper::twilight_exports::{CommandOptionChoice, CommandOptionChoiceValue, InteractionResponseData};
+    ///
+    /// #[autocomplete]
+    /// async fn autocomplete_fruit(ctx: AutocompleteContext<()>) -> Option<InteractionResponseData> {
+    ///     let choices = ["apple", "banana", "cherry"]
+    ///         .into_iter()
+    ///         .filter(|fruit| fruit.starts_with(&ctx.user_input.input))
+    ///         .map(|fruit| CommandOptionChoice {
+    ///             name: fruit.to_string(),
+    ///             name_localizations: None,
+    ///             value: CommandOptionChoiceValue::String(fruit.to_string())
+    ///         })
+    ///         .collect();
+    ///
+    ///     let _ = ctx.respond_with(choices).await;
+    ///     None
+    /// }
+    /// ```
+    pub async fn respond_with(&self, mut choices: Vec<CommandOptionChoice>) -> Result<(), twilight_http::Error> {
+        if choices.len() > AUTOCOMPLETE_CHOICES_LIMIT {
+            warn!(
+                "Autocomplete received {} choices, truncating to {}",
+                choices.len(),
+                AUTOCOMPLETE_CHOICES_LIMIT
+            );
+            choices.truncate(AUTOCOMPLETE_CHOICES_LIMIT);
+        }
+
+        self.http_client()
+            .interaction(self.interaction.application_id)
+            .create_response(
+                self.interaction.id,
+                &self.interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+                    data: Some(InteractionResponseData {
+                        choices: Some(choices),
+                        ..Default::default()
+                    }),
+                },
+            )
+            .await?;
+
+        self.responded.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    /// Responds to the interaction without any suggestion, this is a shorthand for
+    /// [respond_with](Self::respond_with) using an empty list of choices.
+    ///
+    /// Discord keeps the user waiting until the autocomplete interaction is answered, so this
+    /// should be used when there is nothing to suggest.
+    pub async fn respond_empty(&self) -> Result<(), twilight_http::Error> {
+        self.respond_with(Vec::new()).await
+    }
 }
 
 /// Framework context given to all command functions, this struct contains all the necessary
@@ -62,8 +206,23 @@ pub struct SlashContext<'a, D> {
     pub data: &'a D,
     /// Components waiting for an interaction.
     pub waiters: &'a Mutex<Vec<WaiterWaker>>,
+    /// The owners of the bot, set using [owners](crate::builder::FrameworkBuilder::owners).
+    pub owners: &'a HashSet<Id<UserMarker>>,
     /// The interaction itself.
     pub interaction: Interaction,
+    /// Values scoped to this interaction, used to share data between hooks and the command.
+    pub extensions: Extensions,
+    /// Whether the interaction has already been acknowledged.
+    pub(crate) acknowledged: Arc<AtomicBool>,
+    /// Whether a message was sent in response to the interaction, as opposed to only deferring
+    /// it.
+    pub(crate) responded: Arc<AtomicBool>,
+    /// Whether responses are ephemeral by default, set using
+    /// [Command::ephemeral](crate::command::Command::ephemeral).
+    pub(crate) ephemeral: bool,
+    /// Whether the checks of the command are bypassed, set using
+    /// [bypass_checks](Self::bypass_checks).
+    pub(crate) bypass_checks: bool,
 }
 
 impl<'a, D> Clone for SlashContext<'a, D> {
@@ -74,7 +233,13 @@ impl<'a, D> Clone for SlashContext<'a, D> {
             interaction_client: self.http_client.inner().interaction(self.application_id),
             data: self.data,
             waiters: self.waiters,
+            owners: self.owners,
             interaction: self.interaction.clone(),
+            extensions: self.extensions.clone(),
+            acknowledged: Arc::clone(&self.acknowledged),
+            responded: Arc::clone(&self.responded),
+            ephemeral: self.ephemeral,
+            bypass_checks: self.bypass_checks,
         }
     }
 }
@@ -86,6 +251,7 @@ impl<'a, D> SlashContext<'a, D> {
         application_id: Id<ApplicationMarker>,
         data: &'a D,
         waiters: &'a Mutex<Vec<WaiterWaker>>,
+        owners: &'a HashSet<Id<UserMarker>>,
         interaction: Interaction,
     ) -> Self {
         let interaction_client = http_client.inner().interaction(application_id);
@@ -95,7 +261,13 @@ impl<'a, D> SlashContext<'a, D> {
             interaction_client,
             data,
             waiters,
+            owners,
             interaction,
+            extensions: Extensions::new(),
+            acknowledged: Arc::new(AtomicBool::new(false)),
+            responded: Arc::new(AtomicBool::new(false)),
+            ephemeral: false,
+            bypass_checks: false,
         }
     }
 
@@ -104,14 +276,252 @@ impl<'a, D> SlashContext<'a, D> {
         self.http_client.inner()
     }
 
+    /// Gets the raw [interaction](Interaction) being handled.
+    ///
+    /// This is meant for advanced use, to call twilight APIs the framework doesn't wrap.
+    pub fn interaction(&self) -> &Interaction {
+        &self.interaction
+    }
+
+    /// Gets the [interaction client](InteractionClient) bound to the application id of the
+    /// framework.
+    ///
+    /// This is meant for advanced use, responses sent directly using this client bypass the
+    /// tracking done by the framework, so [respond](Self::respond) and [defer](Self::defer) won't
+    /// know the interaction was acknowledged unless [mark_acknowledged](Self::mark_acknowledged)
+    /// is called.
+    pub fn interaction_client(&self) -> &InteractionClient<'a> {
+        &self.interaction_client
+    }
+
+    /// Marks the interaction as acknowledged, used after responding to it without using the
+    /// methods of the context, so later responses edit the original one.
+    pub fn mark_acknowledged(&self) {
+        self.set_acknowledged();
+        self.set_responded();
+    }
+
+    /// Makes the command skip its checks and the checks of its group for this invocation only,
+    /// meant to be called from a `before` hook to allow administrative overrides, like letting
+    /// the owners of the bot run any command:
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[before]
+    /// async fn owner_bypass(ctx: &mut SlashContext<()>, _name: &str) -> bool {
+    ///     if ctx.is_owner() {
+    ///         ctx.bypass_checks();
+    ///     }
+    ///
+    ///     true
+    /// }
+    /// ```
+    ///
+    /// # Security
+    ///
+    /// Checks are often the only thing protecting sensitive commands, so this must only be
+    /// called after verifying who invoked the command. Permissions enforced by the framework,
+    /// cooldowns and locale restrictions still apply.
+    pub fn bypass_checks(&mut self) {
+        self.bypass_checks = true;
+    }
+
+    /// Returns whether the checks are bypassed for this invocation, see
+    /// [bypass_checks](Self::bypass_checks).
+    pub fn checks_bypassed(&self) -> bool {
+        self.bypass_checks
+    }
+
+    /// Gets the user who invoked the command.
+    ///
+    /// In guilds, the user is taken from the invoking [member](PartialMember), while in direct
+    /// messages it is taken from the interaction itself.
+    pub fn author(&self) -> &User {
+        self.interaction.author()
+            .expect("Command interactions always have an author")
+    }
+
+    /// Returns whether the user who invoked the command is one of the owners of the bot.
+    pub fn is_owner(&self) -> bool {
+        self.interaction.author_id().is_some_and(|id| self.owners.contains(&id))
+    }
+
+    /// Returns whether the member who invoked the command has the given permissions in the channel
+    /// the command was used in, members with the `ADMINISTRATOR` permission have all permissions.
+    ///
+    /// Outside of guilds there are no member permissions, so this always returns `false`.
+    pub fn has_permissions(&self, permissions: Permissions) -> bool {
+        self.member()
+            .and_then(|member| member.permissions)
+            .is_some_and(|member_permissions| {
+                member_permissions.contains(Permissions::ADMINISTRATOR)
+                    || member_permissions.contains(permissions)
+            })
+    }
+
+    /// Gets the [member](PartialMember) who invoked the command, only present if the command was
+    /// invoked inside a guild.
+    pub fn member(&self) -> Option<&PartialMember> {
+        self.interaction.member.as_ref()
+    }
+
+    /// Gets the id of the guild the command was invoked in, if any.
+    pub fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        self.interaction.guild_id
+    }
+
+    /// Gets the id of the channel the command was invoked in.
+    #[allow(deprecated)]
+    pub fn channel_id(&self) -> Id<ChannelMarker> {
+        self.interaction.channel.as_ref()
+            .map(|channel| channel.id)
+            .or(self.interaction.channel_id)
+            .expect("Command interactions always have a channel")
+    }
+
+    /// Fetches the message referenced by the given [link](MessageLink).
+    ///
+    /// If the link only contains the id of the message, it is fetched from the channel the
+    /// command was invoked in.
+    pub async fn fetch_message(&self, link: &MessageLink) -> Result<Message, FetchMessageError> {
+        let channel_id = link.channel_id.unwrap_or_else(|| self.channel_id());
+
+        Ok(self.http_client()
+            .message(channel_id, link.message_id)
+            .await?
+            .model()
+            .await?)
+    }
+
+    /// Gets the target of a context menu command, this is, the [message](Message) or user the
+    /// command was used on.
+    ///
+    /// The target is taken from the interaction resolved data, so it can only be retrieved once.
+    pub fn target<T: ParseTarget>(&mut self) -> Result<T, ParseError> {
+        match self.interaction.data.as_mut() {
+            Some(InteractionData::ApplicationCommand(data)) => T::parse_target(data),
+            _ => Err(ParseError::StructureMismatch(String::from("Interaction is not a command")))
+        }
+    }
+
+    /// Parses the values selected in a select menu interaction, such as one received with
+    /// [wait_component](Self::wait_component), into the given type.
+    ///
+    /// Values are parsed according to the [kind](Parse::kind) of `T`, so select menu values can be
+    /// parsed as user or role ids, integers or [choices](macro@crate::macros::Choice) for example.
+    ///
+    /// Since message component interactions don't include resolved data, only types which don't
+    /// need it can be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    /// use vesper::twilight_exports::{Id, RoleMarker};
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     // Send a role select menu...
+    ///
+    ///     let interaction = ctx.wait_component(|_| true).await?;
+    ///     let roles = ctx.select_values::<Id<RoleMarker>>(&interaction).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn select_values<T>(&self, interaction: &Interaction) -> Result<Vec<T>, ParseError>
+    where
+        T: Parse<D>,
+        D: Send + Sync
+    {
+        let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+            return Err(ParseError::StructureMismatch(String::from("Interaction is not a message component")));
+        };
+
+        let mut parsed = Vec::with_capacity(data.values.len());
+
+        for value in &data.values {
+            let value = select_value(T::kind(), value)
+                .ok_or_else(|| ParseError::StructureMismatch(format!("Invalid select menu value: {value}")))?;
+
+            parsed.push(T::parse(self.http_client, self.data, Some(&value), None).await?);
+        }
+
+        Ok(parsed)
+    }
+
     /// Gets a mutable reference to the [interaction](Interaction) owned by the context.
     #[deprecated(since = "0.12.0", note = "Use the `interaction` field directly with a mutable context")]
     pub fn interaction_mut(&mut self) -> &mut Interaction {
         &mut self.interaction
     }
 
+    /// Returns whether the interaction has already been acknowledged, either by responding to it
+    /// or by deferring it.
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged.load(Ordering::Acquire)
+    }
+
+    /// Returns whether the responses to this interaction are ephemeral by default, as set by
+    /// [Command::ephemeral](crate::command::Command::ephemeral).
+    pub fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
+
+    /// Returns the time the interaction was created at, obtained from its id.
+    pub fn created_at(&self) -> SystemTime {
+        let millis = (self.interaction.id.get() >> 22) + DISCORD_EPOCH;
+        UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    /// Returns the time remaining until the interaction token expires, `None` if it already
+    /// expired.
+    ///
+    /// The token is valid for [fifteen minutes](TOKEN_LIFETIME) after the interaction is created,
+    /// so commands running for longer can't respond nor edit their response.
+    pub fn token_remaining(&self) -> Option<Duration> {
+        let elapsed = SystemTime::now()
+            .duration_since(self.created_at())
+            .unwrap_or_default();
+
+        TOKEN_LIFETIME.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Returns whether the interaction token expired, so the response can no longer be sent or
+    /// edited, allowing long running commands to finish before getting an error from discord.
+    pub fn is_token_expired(&self) -> bool {
+        self.token_remaining().is_none()
+    }
+
+    /// Marks the interaction as acknowledged, returning whether it was already acknowledged.
+    pub(crate) fn set_acknowledged(&self) -> bool {
+        self.acknowledged.swap(true, Ordering::AcqRel)
+    }
+
+    /// Marks the interaction as not acknowledged, used when acknowledging it failed.
+    pub(crate) fn unset_acknowledged(&self) {
+        self.acknowledged.store(false, Ordering::Release);
+    }
+
+    /// Returns whether a message was sent in response to the interaction, a deferred interaction
+    /// is acknowledged but not responded until its original response is edited.
+    pub(crate) fn is_responded(&self) -> bool {
+        self.responded.load(Ordering::Acquire)
+    }
+
+    /// Marks the interaction as responded.
+    pub(crate) fn set_responded(&self) {
+        self.responded.store(true, Ordering::Release);
+    }
+
     /// Defers the interaction, allowing to respond later.
     ///
+    /// If the command is [ephemeral](crate::command::Command::ephemeral), the interaction is
+    /// deferred as an ephemeral message. If the interaction has already been acknowledged, this
+    /// does nothing.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -121,12 +531,12 @@ impl<'a, D> SlashContext<'a, D> {
     /// #[description = "My command description"]
     /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
     ///     // Defer the interaction, this way we can respond to it later.
-    ///     ctx.defer(false).await?;
+    ///     ctx.defer().await?;
     ///
     ///     // Do something here
     ///
     ///     // Now edit the interaction
-    ///     ctx.interaction_client.update_response(&ctx.interaction.token)
+    ///     ctx.update_response()
     ///         .content(Some("Hello world"))
     ///         .unwrap()
     ///         .await?;
@@ -134,32 +544,252 @@ impl<'a, D> SlashContext<'a, D> {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn defer(&self, ephemeral: bool) -> Result<(), twilight_http::Error> {
-        self.interaction_client
+    pub async fn defer(&self) -> Result<(), twilight_http::Error> {
+        self.defer_with(self.ephemeral.then_some(MessageFlags::EPHEMERAL)).await
+    }
+
+    /// Defers the interaction as an ephemeral message, so the response will only be visible to
+    /// the user who invoked the command.
+    ///
+    /// If the interaction has already been acknowledged, this does nothing.
+    pub async fn defer_ephemeral(&self) -> Result<(), twilight_http::Error> {
+        self.defer_with(Some(MessageFlags::EPHEMERAL)).await
+    }
+
+    async fn defer_with(&self, flags: Option<MessageFlags>) -> Result<(), twilight_http::Error> {
+        if self.set_acknowledged() {
+            return Ok(());
+        }
+
+        let response = self.interaction_client
             .create_response(
                 self.interaction.id,
                 &self.interaction.token,
                 &InteractionResponse {
                     kind: InteractionResponseType::DeferredChannelMessageWithSource,
-                    data: if ephemeral {
-                        Some(InteractionResponseData {
-                            flags: Some(MessageFlags::EPHEMERAL),
-                            ..Default::default()
-                        })
-                    } else {
-                        None
-                    },
+                    data: flags.map(|flags| InteractionResponseData {
+                        flags: Some(flags),
+                        ..Default::default()
+                    }),
                 },
             )
-            .await?;
+            .await;
+
+        if let Err(why) = response {
+            // The interaction wasn't acknowledged, so allow trying again.
+            self.unset_acknowledged();
+            return Err(why);
+        }
 
         Ok(())
     }
 
+    /// Runs the given future while keeping the user informed, deferring the interaction and
+    /// editing the response every `interval` with the content returned by `progress`, which
+    /// receives the time elapsed since the future started.
+    ///
+    /// The response stops being edited once the [token expires](Self::is_token_expired), errors
+    /// editing the response are ignored so they don't interrupt the future. If an edit is being
+    /// sent when the future completes, it finishes before the output is returned, so it can't
+    /// overwrite the response sent afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use vesper::prelude::*;
+    ///
+    /// async fn generate_report() -> String {
+    ///     // Something that takes a long time.
+    ///     String::new()
+    /// }
+    ///
+    /// #[command]
+    /// #[description = "Generates a report"]
+    /// async fn report(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     let report = ctx.keep_alive(
+    ///         Duration::from_secs(30),
+    ///         |elapsed| format!("Still working, {} seconds elapsed", elapsed.as_secs()),
+    ///         generate_report()
+    ///     ).await?;
+    ///
+    ///     ctx.respond().content(report).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn keep_alive<F, P>(
+        &self,
+        interval: Duration,
+        progress: P,
+        future: F
+    ) -> Result<F::Output, twilight_http::Error>
+    where
+        F: Future,
+        P: Fn(Duration) -> String
+    {
+        self.defer().await?;
+
+        let started = Instant::now();
+        let mut future = pin!(future);
+        let mut timer = pin!(sleep(interval));
+        let mut update: Option<ResponseFuture<Message>> = None;
+
+        let mut output = None;
+
+        poll_fn(|cx| {
+            if output.is_none() {
+                if let Poll::Ready(value) = future.as_mut().poll(cx) {
+                    output = Some(value);
+                }
+            }
+
+            if let Some(request) = &mut update {
+                if Pin::new(request).poll(cx).is_ready() {
+                    update = None;
+                }
+            }
+
+            if output.is_some() {
+                // Finish sending the pending edit, otherwise it could be received after the
+                // response sent once the future completes, overwriting it.
+                return if update.is_none() { Poll::Ready(()) } else { Poll::Pending };
+            }
+
+            if update.is_none() && timer.as_mut().poll(cx).is_ready() {
+                timer.as_mut().reset(tokio::time::Instant::now() + interval);
+
+                if !self.is_token_expired() {
+                    let content = progress(started.elapsed());
+                    let request = self.interaction_client
+                        .update_response(&self.interaction.token)
+                        .content(Some(&content))
+                        .map(IntoFuture::into_future);
+
+                    if let Ok(request) = request {
+                        update = Some(request);
+                    }
+                }
+
+                // Poll the timer and the new request.
+                cx.waker().wake_by_ref();
+            }
+
+            Poll::Pending
+        }).await;
+
+        Ok(output.expect("The future completed"))
+    }
+
+    /// Returns a [builder](ResponseBuilder) used to respond to the interaction, the response is
+    /// sent when the builder is `.await`ed.
+    ///
+    /// If the interaction has already been acknowledged, for example by
+    /// [deferring](Self::defer) it, the original response is edited instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     ctx.respond()
+    ///         .content("Hello world")
+    ///         .ephemeral()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn respond(&self) -> ResponseBuilder<'_, D> {
+        ResponseBuilder::new(self)
+    }
+
+    /// Returns the request used to edit the original response of the interaction, allowing to
+    /// change its content, embeds and components after it has been sent or deferred.
+    ///
+    /// Once this is called, the interaction is considered responded, so the
+    /// [error response](crate::builder::FrameworkBuilder::error_response) won't replace the
+    /// response if the command fails afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     ctx.defer().await?;
+    ///
+    ///     ctx.update_response()
+    ///         .content(Some("Working on it..."))?
+    ///         .await?;
+    ///
+    ///     // Do something here
+    ///
+    ///     ctx.update_response()
+    ///         .content(Some("Done!"))?
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn update_response(&self) -> UpdateResponse<'_> {
+        self.set_responded();
+        self.interaction_client.update_response(&self.interaction.token)
+    }
+
+    /// Deletes the original response of the interaction.
+    pub fn delete_response(&self) -> DeleteResponse<'_> {
+        self.interaction_client.delete_response(&self.interaction.token)
+    }
+
+    /// Creates a followup message for the interaction, returning the request builder used to
+    /// set its content.
+    ///
+    /// Followups can be sent both after responding to the interaction and after deferring it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     ctx.defer().await?;
+    ///
+    ///     ctx.create_followup()
+    ///         .content("Hello from a followup")?
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn create_followup(&self) -> CreateFollowup<'_> {
+        self.interaction_client.create_followup(&self.interaction.token)
+    }
+
+    /// Edits a followup message previously sent using [create_followup](Self::create_followup).
+    pub fn edit_followup(&self, message_id: Id<MessageMarker>) -> UpdateFollowup<'_> {
+        self.interaction_client.update_followup(&self.interaction.token, message_id)
+    }
+
+    /// Deletes a followup message previously sent using [create_followup](Self::create_followup).
+    pub fn delete_followup(&self, message_id: Id<MessageMarker>) -> DeleteFollowup<'_> {
+        self.interaction_client.delete_followup(&self.interaction.token, message_id)
+    }
+
     /// Creates a modal that will be prompted to the user in discord, returning a [`WaitModal`] that
     /// can be `.await`ed to retrieve the user input. If the returned [`WaitModal`] is not awaited,
     /// the modal will not close when submitted and the user won't be able to submit the modal.
     ///
+    /// Modals must be the first response to an interaction, so this fails with
+    /// [AlreadyAcknowledged](ModalError::AlreadyAcknowledged) if it was already acknowledged, for
+    /// example by deferring it.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -186,26 +816,107 @@ impl<'a, D> SlashContext<'a, D> {
     /// ```
     ///
     /// [`WaitModal`]: WaitModal
-    pub async fn create_modal<M>(&self) -> Result<WaitModal<M>, twilight_http::Error>
+    pub async fn create_modal<M>(&self) -> Result<WaitModal<'_, M>, ModalError>
     where
         M: Modal<D>
     {
         let modal_id = self.interaction.id.to_string();
-        self.interaction_client.create_response(
-            self.interaction.id,
-            &self.interaction.token,
-            &M::create(self, modal_id.clone())
-        ).await?;
+        self.send_modal(&M::create(self, modal_id.clone())).await?;
+
+        Ok(WaitModal::new(self.wait_modal_submit(modal_id), &self.interaction_client, M::parse))
+    }
+
+    /// Responds to the interaction with the given [modal](ModalBuilder), returning a [`WaitModal`]
+    /// that can be `.await`ed to retrieve the submitted data.
+    ///
+    /// The modal is validated before being sent, returning an error if it isn't valid. If the
+    /// modal doesn't have a custom id, the interaction id is used.
+    ///
+    /// Like [create_modal](Self::create_modal), this fails if the interaction was already
+    /// acknowledged.
+    ///
+    /// [`WaitModal`]: WaitModal
+    pub async fn respond_with_modal(
+        &self,
+        modal: ModalBuilder
+    ) -> Result<WaitModal<'_, ModalInteractionData>, ModalError> {
+        let modal = if modal.get_custom_id().is_empty() {
+            modal.custom_id(self.interaction.id.to_string())
+        } else {
+            modal
+        };
+        let custom_id = modal.get_custom_id().to_string();
+        let response = modal.build()?;
+
+        self.send_modal(&response).await?;
+
+        Ok(WaitModal::new(self.wait_modal_submit(custom_id), &self.interaction_client, modal_data))
+    }
+
+    /// Responds to the interaction with the given modal, failing if the interaction was already
+    /// acknowledged.
+    async fn send_modal(&self, response: &InteractionResponse) -> Result<(), ModalError> {
+        if self.set_acknowledged() {
+            return Err(ModalError::AlreadyAcknowledged);
+        }
 
-        let waiter = self.wait_interaction(move |interaction| {
+        let result = self.interaction_client
+            .create_response(self.interaction.id, &self.interaction.token, response)
+            .await;
+
+        if let Err(why) = result {
+            // The interaction wasn't acknowledged, so allow trying again.
+            self.unset_acknowledged();
+            return Err(why.into());
+        }
+
+        self.set_responded();
+        Ok(())
+    }
+
+    /// Returns a [`WaitModal`] used to retrieve the data of a modal with the given custom id, this
+    /// is useful when the modal is not created by [create_modal](Self::create_modal), for example,
+    /// when it is sent as the response of a button interaction.
+    ///
+    /// Once the modal is submitted, the waiter acknowledges the interaction and returns the
+    /// submitted [`ModalInteractionData`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     // Respond to an interaction with a modal whose custom id is "feedback"...
+    ///
+    ///     let outcome = ctx.wait_modal("feedback").await?;
+    ///     for row in &outcome.components {
+    ///         for field in &row.components {
+    ///             println!("{}: {:?}", field.custom_id, field.value);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`WaitModal`]: WaitModal
+    /// [`ModalInteractionData`]: ModalInteractionData
+    pub fn wait_modal(&self, custom_id: impl Into<String>) -> WaitModal<'_, ModalInteractionData> {
+        WaitModal::new(self.wait_modal_submit(custom_id.into()), &self.interaction_client, modal_data)
+    }
+
+    /// Returns a waiter used to wait for the submission of the modal with the given custom id.
+    fn wait_modal_submit(&self, custom_id: String) -> InteractionWaiter {
+        self.wait_interaction(move |interaction| {
             let Some(InteractionData::ModalSubmit(data)) = &interaction.data else {
                 return false;
             };
 
-            data.custom_id == modal_id
-        });
-
-        Ok(WaitModal::new(waiter, &self.interaction_client, M::parse))
+            data.custom_id == custom_id
+        })
     }
 
     /// Returns a waiter used to wait for a specific interaction which satisfies the provided
@@ -215,8 +926,214 @@ impl<'a, D> SlashContext<'a, D> {
         F: Fn(&Interaction) -> bool + Send + 'static
     {
         let (waker, waiter) = new_pair(fun);
+        self.register_waker(waker);
+        waiter
+    }
+
+    fn register_waker(&self, waker: WaiterWaker) {
         let mut lock = self.waiters.lock();
+        // Remove the wakers whose waiters were dropped, for example, because they timed out.
+        lock.retain(|waker| !waker.is_closed());
         lock.push(waker);
-        waiter
     }
+
+    /// Returns a waiter used to wait for a message component interaction, such as a button press
+    /// or a select menu choice, which satisfies the provided closure.
+    ///
+    /// Waiters are checked in the order they were created, and the interaction is delivered to
+    /// the first one whose closure returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     let author = ctx.interaction.author_id();
+    ///
+    ///     // Only accept the components used by the user who invoked the command.
+    ///     let interaction = ctx.wait_component(move |interaction| {
+    ///         interaction.author_id() == author
+    ///     }).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn wait_component<F>(&self, fun: F) -> InteractionWaiter
+    where
+        F: Fn(&Interaction) -> bool + Send + 'static
+    {
+        self.wait_interaction(move |interaction| {
+            interaction.kind == InteractionType::MessageComponent && fun(interaction)
+        })
+    }
+
+    /// Returns a [stream](InteractionStream) yielding all the message component interactions
+    /// which satisfy the provided closure, useful for paginators or multi-step flows.
+    pub fn wait_component_stream<F>(&self, fun: F) -> InteractionStream
+    where
+        F: Fn(&Interaction) -> bool + Send + 'static
+    {
+        let (waker, stream) = new_stream_pair(move |interaction: &Interaction| {
+            interaction.kind == InteractionType::MessageComponent && fun(interaction)
+        });
+        self.register_waker(waker);
+        stream
+    }
+
+    /// Waits for a specific interaction which satisfies the provided closure, returning `Ok(None)`
+    /// if it isn't received within the given duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "My command description"]
+    /// async fn my_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     let interaction = ctx.wait_interaction_timeout(|interaction| {
+    ///         // predicate here
+    ///         false
+    ///     }, Duration::from_secs(60)).await?;
+    ///
+    ///     if interaction.is_none() {
+    ///         // No interaction was received in a minute.
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_interaction_timeout<F>(
+        &self,
+        fun: F,
+        duration: Duration
+    ) -> Result<Option<Interaction>, RecvError>
+    where
+        F: Fn(&Interaction) -> bool + Send + 'static
+    {
+        self.wait_interaction(fun).timeout(duration).await
+    }
+
+    /// Responds with the given content along with "Yes" and "No" buttons, waiting for the user
+    /// who invoked the command to press one of them.
+    ///
+    /// Returns `Ok(Some(true))` if the user confirmed, `Ok(Some(false))` if the user declined, or
+    /// `Ok(None)` if no button was pressed within the given duration. In every case the buttons are
+    /// disabled afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use vesper::prelude::*;
+    ///
+    /// #[command]
+    /// #[description = "Deletes everything"]
+    /// async fn delete_everything(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    ///     match ctx.confirm("Are you sure?", Duration::from_secs(30)).await? {
+    ///         Some(true) => { /* Delete everything */ },
+    ///         Some(false) => { /* The user declined */ },
+    ///         None => { /* The user didn't answer in time */ }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn confirm(
+        &self,
+        content: impl Into<String>,
+        duration: Duration
+    ) -> Result<Option<bool>, ConfirmError>
+    where
+        D: Sync
+    {
+        let prefix = format!("{}:confirm:", self.interaction.id);
+        let yes = format!("{}yes", prefix);
+        let no = format!("{}no", prefix);
+
+        let buttons = |disabled: bool| -> Result<Vec<Component>, ComponentError> {
+            let row = ActionRowBuilder::new()
+                .button(ButtonBuilder::new(ButtonStyle::Primary, &yes).label("Yes").disabled(disabled))
+                .button(ButtonBuilder::new(ButtonStyle::Secondary, &no).label("No").disabled(disabled));
+
+            Ok(vec![row.build()?])
+        };
+        let enabled = buttons(false).map_err(ResponseError::from)?;
+        let disabled = buttons(true).map_err(ResponseError::from)?;
+
+        self.respond()
+            .content(content)
+            .components(enabled)
+            .await?;
+
+        let author = self.interaction.author_id();
+        let interaction = self.wait_component(move |interaction| {
+            let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+                return false;
+            };
+
+            data.custom_id.starts_with(&prefix) && interaction.author_id() == author
+        }).timeout(duration).await?;
+
+        let Some(interaction) = interaction else {
+            self.update_response()
+                .components(Some(&disabled))
+                .map_err(ResponseError::from)?
+                .await
+                .map_err(ResponseError::from)?;
+
+            return Ok(None);
+        };
+
+        let confirmed = matches!(
+            &interaction.data,
+            Some(InteractionData::MessageComponent(data)) if data.custom_id == yes
+        );
+
+        self.interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::UpdateMessage,
+                    data: Some(InteractionResponseData {
+                        components: Some(disabled),
+                        ..Default::default()
+                    })
+                }
+            )
+            .await
+            .map_err(ResponseError::from)?;
+
+        Ok(Some(confirmed))
+    }
+}
+
+/// Converts a select menu value into the option value of the given kind.
+fn select_value(kind: CommandOptionType, value: &str) -> Option<CommandOptionValue> {
+    Some(match kind {
+        CommandOptionType::String => CommandOptionValue::String(value.to_string()),
+        CommandOptionType::Integer => CommandOptionValue::Integer(value.parse().ok()?),
+        CommandOptionType::Number => CommandOptionValue::Number(value.parse().ok()?),
+        CommandOptionType::Boolean => CommandOptionValue::Boolean(value.parse().ok()?),
+        CommandOptionType::User => CommandOptionValue::User(value.parse().ok()?),
+        CommandOptionType::Channel => CommandOptionValue::Channel(value.parse().ok()?),
+        CommandOptionType::Role => CommandOptionValue::Role(value.parse().ok()?),
+        CommandOptionType::Mentionable => CommandOptionValue::Mentionable(value.parse().ok()?),
+        CommandOptionType::Attachment => CommandOptionValue::Attachment(value.parse().ok()?),
+        _ => return None
+    })
+}
+
+/// Extracts the data of a submitted modal.
+fn modal_data(interaction: &mut Interaction) -> ModalInteractionData {
+    let Some(InteractionData::ModalSubmit(data)) = &interaction.data else {
+        unreachable!();
+    };
+
+    data.clone()
 }
@@ -0,0 +1,102 @@
+use crate::twilight_exports::Interaction;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The scope a [cooldown](Cooldown) is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CooldownBucket {
+    /// Each user has its own cooldown.
+    User,
+    /// Each guild has its own cooldown, commands used in direct messages have no cooldown.
+    Guild,
+    /// Each channel has its own cooldown, commands used without a known channel have no cooldown.
+    Channel,
+    /// The cooldown is shared by everyone using the command.
+    Global
+}
+
+impl CooldownBucket {
+    /// Returns the key used to store the cooldown of the given interaction, or `None` if the
+    /// interaction doesn't include the id of the bucket, so it isn't shared with unrelated ones.
+    fn key(&self, interaction: &Interaction) -> Option<u64> {
+        match self {
+            Self::User => interaction.author_id().map(|id| id.get()),
+            Self::Guild => interaction.guild_id.map(|id| id.get()),
+            #[allow(deprecated)]
+            Self::Channel => interaction.channel.as_ref()
+                .map(|channel| channel.id)
+                .or(interaction.channel_id)
+                .map(|id| id.get()),
+            // Snowflakes are never zero, so it can't collide with any other key.
+            Self::Global => Some(0)
+        }
+    }
+}
+
+/// A cooldown limiting how often a command can be used, once a command is executed, it can't be
+/// executed again in the same [bucket](CooldownBucket) until the duration of the cooldown passes.
+///
+/// When a command is on cooldown, its execution finishes with
+/// [OnCooldown](crate::command::ExecutionState::OnCooldown), containing the remaining time.
+#[derive(Debug)]
+pub struct Cooldown {
+    /// The time that must pass between two uses of the command.
+    pub duration: Duration,
+    /// The scope of the cooldown.
+    pub bucket: CooldownBucket,
+    /// The instant when the cooldown of the stored keys expires.
+    expirations: Mutex<HashMap<u64, Instant>>
+}
+
+impl Cooldown {
+    /// Creates a new cooldown with the given duration, applied to the given bucket.
+    pub fn new(duration: Duration, bucket: CooldownBucket) -> Self {
+        Self {
+            duration,
+            bucket,
+            expirations: Default::default()
+        }
+    }
+
+    /// Returns the remaining cooldown for the given interaction, if any.
+    pub fn remaining(&self, interaction: &Interaction) -> Option<Duration> {
+        let key = self.bucket.key(interaction)?;
+        let now = Instant::now();
+
+        self.expirations.lock()
+            .get(&key)
+            .and_then(|expiration| expiration.checked_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Resets the cooldown for the given interaction, allowing the command to be used again.
+    pub fn reset(&self, interaction: &Interaction) {
+        if let Some(key) = self.bucket.key(interaction) {
+            self.expirations.lock().remove(&key);
+        }
+    }
+
+    /// Starts the cooldown for the given interaction, returning the remaining time instead if the
+    /// interaction is already on cooldown.
+    pub(crate) fn trigger(&self, interaction: &Interaction) -> Result<(), Duration> {
+        let Some(key) = self.bucket.key(interaction) else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let mut lock = self.expirations.lock();
+
+        if let Some(remaining) = lock.get(&key)
+            .and_then(|expiration| expiration.checked_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+        {
+            return Err(remaining);
+        }
+
+        // Remove the expired cooldowns so the map doesn't grow indefinitely.
+        lock.retain(|_, expiration| *expiration > now);
+        lock.insert(key, now + self.duration);
+
+        Ok(())
+    }
+}
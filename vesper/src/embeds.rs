@@ -0,0 +1,173 @@
+//! A builder used to create embeds, validating them against discord limits.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use vesper::prelude::*;
+//! use vesper::embeds::EmbedBuilder;
+//!
+//! #[command]
+//! #[description = "Shows the stats of the bot"]
+//! async fn stats(ctx: &SlashContext<()>) -> DefaultCommandResult {
+//!     ctx.respond()
+//!         .embed(EmbedBuilder::new()
+//!             .title("Stats")
+//!             .color(0x5865f2)
+//!             .field("Guilds", "10", true)
+//!             .field("Users", "250", true)
+//!             .footer("Updated every minute"))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use thiserror::Error;
+use twilight_model::channel::message::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter};
+use twilight_model::util::Timestamp;
+use twilight_validate::embed::{chars, embed, EmbedValidationError, EMBED_TOTAL_LENGTH};
+use twilight_validate::message::EMBED_COUNT_LIMIT;
+
+/// Errors returned when building or sending embeds exceeding discord limits.
+#[derive(Debug, Error)]
+pub enum EmbedError {
+    /// More than [EMBED_COUNT_LIMIT] embeds were provided.
+    #[error("A message can't have more than {EMBED_COUNT_LIMIT} embeds, but {count} were provided")]
+    TooMany {
+        /// The amount of embeds provided.
+        count: usize
+    },
+    /// The embeds of a message have more than [EMBED_TOTAL_LENGTH] characters combined.
+    #[error("The embeds of a message can have at most {EMBED_TOTAL_LENGTH} characters combined, but they have {chars}")]
+    TooLarge {
+        /// The combined amount of characters of the embeds.
+        chars: usize
+    },
+    /// An embed did not pass discord validation, like having more than 25 fields.
+    #[error(transparent)]
+    Validation(#[from] EmbedValidationError)
+}
+
+/// A builder used to create an [embed](Embed).
+#[derive(Debug, Clone)]
+pub struct EmbedBuilder {
+    embed: Embed
+}
+
+impl EmbedBuilder {
+    /// Creates a new, empty, embed.
+    pub fn new() -> Self {
+        Self {
+            embed: Embed {
+                author: None,
+                color: None,
+                description: None,
+                fields: Vec::new(),
+                footer: None,
+                image: None,
+                kind: String::from("rich"),
+                provider: None,
+                thumbnail: None,
+                timestamp: None,
+                title: None,
+                url: None,
+                video: None
+            }
+        }
+    }
+
+    /// Sets the title of the embed.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.embed.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description of the embed.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.embed.description = Some(description.into());
+        self
+    }
+
+    /// Sets the url the title of the embed links to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.embed.url = Some(url.into());
+        self
+    }
+
+    /// Sets the color of the embed, as a RGB value like `0xff0000`.
+    pub fn color(mut self, color: u32) -> Self {
+        self.embed.color = Some(color);
+        self
+    }
+
+    /// Adds a field with the given name and value, inline fields are shown next to each other.
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        self.embed.fields.push(EmbedField {
+            inline,
+            name: name.into(),
+            value: value.into()
+        });
+        self
+    }
+
+    /// Sets the text of the footer of the embed.
+    pub fn footer(mut self, text: impl Into<String>) -> Self {
+        self.embed.footer = Some(EmbedFooter {
+            icon_url: None,
+            proxy_icon_url: None,
+            text: text.into()
+        });
+        self
+    }
+
+    /// Sets the name of the author of the embed.
+    pub fn author(mut self, name: impl Into<String>) -> Self {
+        self.embed.author = Some(EmbedAuthor {
+            icon_url: None,
+            name: name.into(),
+            proxy_icon_url: None,
+            url: None
+        });
+        self
+    }
+
+    /// Sets the timestamp shown in the footer of the embed.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.embed.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Validates the embed, returning it.
+    pub fn build(self) -> Result<Embed, EmbedError> {
+        embed(&self.embed)?;
+        Ok(self.embed)
+    }
+}
+
+impl Default for EmbedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Validates the amount and combined length of the given embeds, as well as each of the embeds,
+/// against discord limits.
+///
+/// Responses sent using a [response builder](crate::response::ResponseBuilder) are validated
+/// automatically, this can be used to validate the embeds of followups.
+pub fn validate_embeds(embeds: &[Embed]) -> Result<(), EmbedError> {
+    if embeds.len() > EMBED_COUNT_LIMIT {
+        return Err(EmbedError::TooMany { count: embeds.len() });
+    }
+
+    let total = embeds.iter().map(chars).sum();
+    if total > EMBED_TOTAL_LENGTH {
+        return Err(EmbedError::TooLarge { chars: total });
+    }
+
+    for current in embeds {
+        embed(current)?;
+    }
+
+    Ok(())
+}
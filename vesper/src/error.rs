@@ -1,12 +1,210 @@
 use thiserror::Error;
 use twilight_validate::command::CommandValidationError;
 use twilight_http::{Error as HttpError, response::DeserializeBodyError};
+use tokio::sync::oneshot::error::RecvError;
+use crate::response::ResponseError;
 
 #[non_exhaustive]
 #[derive(Debug, Error)]
-#[error(transparent)]
 pub enum CreateCommandError {
+    #[error(transparent)]
     Validation(#[from] CommandValidationError),
+    #[error(transparent)]
     Http(#[from] HttpError),
+    #[error(transparent)]
+    Deserialize(#[from] DeserializeBodyError),
+    /// A localized name or description of a command option is not valid.
+    #[error("Localization of option {option} for locale {locale} is not valid: {source}")]
+    Localization {
+        /// The name of the option the localization belongs to.
+        option: String,
+        /// The locale of the invalid localization.
+        locale: String,
+        source: CommandValidationError
+    },
+    /// A command, or one of its subcommands, is not accepted by discord.
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    /// A command could not be registered, even after
+    /// [retrying](crate::builder::FrameworkBuilder::registration_attempts).
+    #[error("Failed to register command {command} after {attempts} attempts: {source}")]
+    Registration {
+        /// The name of the command.
+        command: String,
+        /// The amount of attempts made.
+        attempts: u32,
+        /// The error returned by the last attempt, including the response of discord.
+        source: HttpError
+    }
+}
+
+/// Errors returned when [fetching the application id](crate::builder::FrameworkBuilder::fetch_application_id)
+/// of the current application.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum FetchApplicationError {
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error(transparent)]
+    Deserialize(#[from] DeserializeBodyError)
+}
+
+/// Errors returned when [fetching the owners](crate::builder::FrameworkBuilder::fetch_owners) of
+/// the application.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum FetchOwnersError {
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error(transparent)]
+    Deserialize(#[from] DeserializeBodyError)
+}
+
+/// Errors returned when [fetching a message](crate::context::SlashContext::fetch_message).
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum FetchMessageError {
+    #[error(transparent)]
+    Http(#[from] HttpError),
+    #[error(transparent)]
     Deserialize(#[from] DeserializeBodyError)
 }
+
+/// Errors returned when [asking for confirmation](crate::context::SlashContext::confirm).
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ConfirmError {
+    /// The prompt could not be sent or updated.
+    #[error(transparent)]
+    Response(#[from] ResponseError),
+    /// The waiter was dropped before receiving the answer.
+    #[error(transparent)]
+    Wait(#[from] RecvError)
+}
+
+/// Problems found while [validating](crate::framework::Framework::validate) the commands of the
+/// framework, which would make discord reject them when registering them.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    /// A command has more than one argument with the same name.
+    #[error("Command {command} has more than one argument named {argument}")]
+    DuplicateArgument {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The name of the repeated argument.
+        argument: String
+    },
+    /// A required argument is declared after an optional one.
+    #[error("Required argument {argument} of command {command} is declared after an optional argument")]
+    RequiredAfterOptional {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The name of the required argument.
+        argument: String
+    },
+    /// The description of a chat command, subcommand or group is empty or longer than the 100
+    /// characters allowed by discord.
+    #[error("Description of {command} must have between 1 and 100 characters, but has {length}")]
+    InvalidDescription {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The amount of characters of the description.
+        length: usize
+    },
+    /// The name of a command, subcommand or group is not accepted by discord.
+    #[error("Name of {command} is not valid, {reason}")]
+    InvalidName {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The rule the name doesn't follow.
+        reason: &'static str
+    },
+    /// The name of an option registered by an argument is not accepted by discord, variadic
+    /// arguments register their options as `<name>_1`, `<name>_2`...
+    #[error("Option {argument} of command {command} is not valid, {reason}")]
+    InvalidArgumentName {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The name of the option.
+        argument: String,
+        /// The rule the name doesn't follow.
+        reason: &'static str
+    },
+    /// An argument given to [require_one_of](crate::command::Command::require_one_of) is not an
+    /// optional argument of the command, so the command would fail on every execution or the
+    /// requirement would never be needed.
+    #[error("Argument {argument} required by require_one_of on command {command} {reason}")]
+    InvalidRequiredArgument {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The name given to `require_one_of`.
+        argument: String,
+        /// Why the argument can't be used.
+        reason: &'static str
+    },
+    /// A command registers more than the 25 options allowed by discord.
+    #[error("Command {command} registers {amount} options, but at most 25 are allowed")]
+    TooManyOptions {
+        /// The full name of the command, including its parents.
+        command: String,
+        /// The amount of options registered by the arguments of the command.
+        amount: usize
+    }
+}
+
+impl ValidationError {
+    /// Returns an error if the given name is not accepted by discord, names of chat commands,
+    /// subcommands and groups must be lowercase and can only contain letters, numbers, `-` and
+    /// `_`, while context menu commands can use any character.
+    pub(crate) fn check_name(path: &str, name: &str, chat: bool) -> Result<(), Self> {
+        match Self::name_rule(name, chat) {
+            Some(reason) => Err(Self::InvalidName {
+                command: path.to_string(),
+                reason
+            }),
+            None => Ok(())
+        }
+    }
+
+    /// Returns an error if the name of the given option, registered by an argument of the
+    /// command at `path`, is not accepted by discord. These follow the rules of chat commands.
+    pub(crate) fn check_argument_name(path: &str, name: &str) -> Result<(), Self> {
+        match Self::name_rule(name, true) {
+            Some(reason) => Err(Self::InvalidArgumentName {
+                command: path.to_string(),
+                argument: name.to_string(),
+                reason
+            }),
+            None => Ok(())
+        }
+    }
+
+    /// Returns the naming rule the given name doesn't follow, if any.
+    fn name_rule(name: &str, chat: bool) -> Option<&'static str> {
+        if !(1..=32).contains(&name.chars().count()) {
+            return Some("names must have between 1 and 32 characters");
+        }
+
+        let valid = |c: char| (c.is_alphanumeric() || c == '-' || c == '_') && !c.is_uppercase();
+        if chat && !name.chars().all(valid) {
+            return Some("names must be lowercase and can only contain letters, numbers, `-` and `_`");
+        }
+
+        None
+    }
+
+    /// Returns an error if the given description is not accepted by discord.
+    pub(crate) fn check_description(path: &str, description: &str) -> Result<(), Self> {
+        let length = description.chars().count();
+
+        if (1..=100).contains(&length) {
+            Ok(())
+        } else {
+            Err(Self::InvalidDescription {
+                command: path.to_string(),
+                length
+            })
+        }
+    }
+}
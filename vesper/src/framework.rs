@@ -1,20 +1,28 @@
 use crate::{
-    argument::CommandArgument,
     builder::{FrameworkBuilder, WrappedClient},
-    command::{Command, CommandMap, ExecutionState, OutputLocation},
+    command::{Command, CommandMap, CommandRef, ExecutionState, OutputLocation, RuntimeCommandMap},
     context::{AutocompleteContext, Focused, SlashContext},
-    group::GroupParentMap,
-    hook::{AfterHook, BeforeHook},
+    group::{GroupParent, GroupParentMap},
+    router::{split_custom_id, ComponentMap},
+    hook::{AfterHook, AutocompleteFn, BeforeHook, ErrorHandlerHook, ExecutionHook, UnknownCommandHook},
+    iter::command_path,
     twilight_exports::{
-        ApplicationMarker, Client,
-        Command as TwilightCommand, CommandDataOption, CommandOptionType,
+        ApplicationMarker, Client, Permissions, UserMarker,
+        Command as TwilightCommand, CommandType, CommandDataOption, CommandOptionType,
         CommandOptionValue, GuildMarker, Id, Interaction, InteractionData, InteractionType, InteractionClient, InteractionResponse,
-        InteractionResponseType,
+        InteractionResponseData, InteractionResponseType,
     },
-    wait::WaiterWaker, prelude::CreateCommandError
+    sync::{CommandDefinition, SyncSummary},
+    wait::WaiterWaker, prelude::CreateCommandError, error::ValidationError
 };
-use tracing::debug;
-use parking_lot::Mutex;
+use tracing::{debug, info, warn};
+use twilight_http::{api_error::ApiError, error::{Error as HttpError, ErrorType}};
+use parking_lot::{Mutex, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 use crate::command::ExecutionResult;
 use crate::parse::ParseError;
 #[cfg(feature = "bulk")]
@@ -29,6 +37,25 @@ macro_rules! extract {
     };
 }
 
+/// The longest time waited before retrying a failed registration.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Returns the time to wait before retrying a request which failed with the given error, or
+/// `None` if the error is not transient.
+fn retry_delay(error: &HttpError, attempt: u32) -> Option<Duration> {
+    let backoff = 2u64.saturating_pow(attempt.saturating_sub(1)).saturating_mul(500);
+    let backoff = Duration::from_millis(backoff).min(MAX_RETRY_DELAY);
+
+    match error.kind() {
+        ErrorType::Response { error: ApiError::Ratelimited(ratelimit), .. } => {
+            Some(Duration::from_secs_f64(ratelimit.retry_after.max(0.0)))
+        },
+        ErrorType::Response { status, .. } if status.get() == 429 || status.is_server_error() => Some(backoff),
+        ErrorType::RequestError | ErrorType::RequestTimedOut | ErrorType::ServiceUnavailable { .. } => Some(backoff),
+        _ => None
+    }
+}
+
 /// The result of a `.process` call, containing the state of the interaction handling.
 #[non_exhaustive]
 pub enum ProcessResult<T, E> {
@@ -39,6 +66,8 @@ pub enum ProcessResult<T, E> {
     ModalSubmit,
     /// The interaction was a message component interaction.
     MessageComponent,
+    /// A [component handler](crate::hook::ComponentHook) was executed, containing its output.
+    ComponentExecuted(Result<T, E>),
     /// The specified command argument was autocompleted successufully.
     Autocompleted,
     /// The specified command was executed.
@@ -63,13 +92,38 @@ pub struct Framework<D, T = (), E = DefaultError> {
     pub data: D,
     /// A map of simple commands.
     pub commands: CommandMap<D, T, E>,
+    /// A map of message commands.
+    pub message_commands: CommandMap<D, T, E>,
+    /// A map of user commands.
+    pub user_commands: CommandMap<D, T, E>,
     /// A map of command groups including all children.
     pub groups: GroupParentMap<D, T, E>,
-    /// A hook executed before the command.
-    pub before: Option<BeforeHook<D>>,
-    /// A hook executed after command's execution.
-    pub after: Option<AfterHook<D, T, E>>,
-    pub waiters: Mutex<Vec<WaiterWaker>>
+    /// The hooks executed before the command, in registration order.
+    pub before: Vec<BeforeHook<D>>,
+    /// The hooks executed after command's execution, in registration order.
+    pub after: Vec<AfterHook<D, T, E>>,
+    /// A hook executed after processing any command, whatever the outcome was.
+    pub execution: Option<ExecutionHook<D, T, E>>,
+    /// The error handler used by commands which don't have one, neither them nor their group.
+    pub error_handler: Option<ErrorHandlerHook<D, E>>,
+    /// The response sent when a command raises an error which isn't handled by any error handler.
+    pub error_response: Option<InteractionResponseData>,
+    /// A hook executed when discord sends a command not provided to the framework.
+    pub unknown_command: Option<UnknownCommandHook<D>>,
+    /// The handlers of message components, by the custom id prefix they handle.
+    pub components: ComponentMap<D, T, E>,
+    /// The owners of the bot.
+    pub owners: HashSet<Id<UserMarker>>,
+    /// Whether the required permissions of commands are checked against the member permissions
+    /// before executing them.
+    pub enforce_permissions: bool,
+    /// Whether the `register_*` methods only log the commands instead of registering them.
+    pub dry_run: bool,
+    /// The maximum amount of attempts made to register each command.
+    pub registration_attempts: u32,
+    pub waiters: Mutex<Vec<WaiterWaker>>,
+    /// The commands registered after building the framework.
+    runtime_commands: RwLock<RuntimeCommandMap<D, T, E>>
 }
 
 impl<D, T, E> Framework<D, T, E>
@@ -77,16 +131,34 @@ where
     E: From<ParseError>
 {
     pub(crate) fn from_builder(builder: FrameworkBuilder<D, T, E>) -> Self {
-        Self {
+        let framework = Self {
             http_client: builder.http_client,
             application_id: builder.application_id,
             data: builder.data,
             commands: builder.commands,
+            message_commands: builder.message_commands,
+            user_commands: builder.user_commands,
             groups: builder.groups,
             before: builder.before,
             after: builder.after,
-            waiters: Mutex::new(Vec::new())
+            execution: builder.execution,
+            error_handler: builder.error_handler,
+            error_response: builder.error_response,
+            unknown_command: builder.unknown_command,
+            components: builder.components,
+            owners: builder.owners,
+            enforce_permissions: builder.enforce_permissions,
+            dry_run: builder.dry_run,
+            registration_attempts: builder.registration_attempts,
+            waiters: Mutex::new(Vec::new()),
+            runtime_commands: RwLock::new(HashMap::new())
+        };
+
+        for error in framework.validate() {
+            warn!("{}", error);
         }
+
+        framework
     }
 
     /// Creates a new framework builder, this is a shortcut to FrameworkBuilder.
@@ -99,6 +171,11 @@ where
         FrameworkBuilder::new(http_client, application_id, data)
     }
 
+    /// Gets the id of the application the framework registers commands for.
+    pub fn application_id(&self) -> Id<ApplicationMarker> {
+        self.application_id
+    }
+
     /// Gets the http client used by the framework.
     pub fn http_client(&self) -> &Client {
         self.http_client.inner()
@@ -107,23 +184,26 @@ where
     /// Gets the [interaction client](InteractionClient) using this framework's
     /// [http client](Client) and [application id](ApplicationMarker)
     pub fn interaction_client(&self) -> InteractionClient {
-        self.http_client().interaction(self.application_id)
+        self.http_client.interaction(self.application_id)
     }
 
     /// Processes the given interaction, dispatching commands or waking waiters if necessary.
-    pub async fn process(&self, mut interaction: Interaction) -> ProcessResult<T, E> {
+    pub async fn process(&self, interaction: Interaction) -> ProcessResult<T, E> {
         match interaction.kind {
             InteractionType::ApplicationCommand => {
-                let Some(command) = self.get_command(&mut interaction) else {
-                    self.wake_waiters(interaction);
+                let Some(command) = self.find_command(&interaction) else {
+                    if let Some(interaction) = self.wake_waiters(interaction) {
+                        self.handle_unknown_command(interaction).await;
+                    }
                     return ProcessResult::CommandNotFound;
                 };
-                self.execute(command, interaction).await.into()
+                let group = self.get_group(&interaction);
+                self.execute(&command, group, interaction).await.into()
             },
             InteractionType::ApplicationCommandAutocomplete => self.try_autocomplete(interaction).await,
-            InteractionType::MessageComponent  => {
-                self.wake_waiters(interaction);
-                ProcessResult::MessageComponent
+            InteractionType::MessageComponent  => match self.wake_waiters(interaction) {
+                Some(interaction) => self.try_component(interaction).await,
+                None => ProcessResult::MessageComponent
             },
             InteractionType::ModalSubmit => {
                 self.wake_waiters(interaction);
@@ -133,39 +213,107 @@ where
         }
     }
 
-    fn wake_waiters(&self, interaction: Interaction) {
+    /// Handles any kind of interaction, routing it to the matching command, autocomplete
+    /// handler, component handler or waiter. This is an alias of [process](Self::process).
+    pub async fn handle_interaction(&self, interaction: Interaction) -> ProcessResult<T, E> {
+        self.process(interaction).await
+    }
+
+    /// Delivers the interaction to the first waiter waiting for it, returning the interaction
+    /// back if no waiter did.
+    fn wake_waiters(&self, interaction: Interaction) -> Option<Interaction> {
         let mut lock = self.waiters.lock();
-        if let Some(position) = lock.iter().position(|waker| waker.check(&interaction)) {
-            lock.remove(position).wake(interaction);
+        lock.retain(|waker| !waker.is_closed());
+        let Some(position) = lock.iter().position(|waker| waker.check(&interaction)) else {
+            return Some(interaction);
+        };
+
+        if let Some(waker) = lock.remove(position).wake(interaction) {
+            lock.insert(position, waker);
         }
+
+        None
+    }
+
+    /// Executes the [unknown command](FrameworkBuilder::unknown_command) hook, if set.
+    async fn handle_unknown_command(&self, interaction: Interaction) {
+        let Some(hook) = &self.unknown_command else {
+            return;
+        };
+
+        let Some(InteractionData::ApplicationCommand(data)) = &interaction.data else {
+            return;
+        };
+        let name = command_path(data).join(" ");
+
+        let mut context = SlashContext::new(
+            &self.http_client,
+            self.application_id,
+            &self.data,
+            &self.waiters,
+            &self.owners,
+            interaction,
+        );
+
+        debug!("Executing unknown command hook for command [{}]", name);
+        (hook.0)(&mut context, &name).await;
+    }
+
+    /// Executes the component handler registered for the custom id of the given interaction.
+    async fn try_component(&self, interaction: Interaction) -> ProcessResult<T, E> {
+        let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+            return ProcessResult::MessageComponent;
+        };
+        let custom_id = data.custom_id.clone();
+        let (prefix, payload) = split_custom_id(&custom_id);
+
+        let Some(hook) = self.components.get(prefix) else {
+            return ProcessResult::MessageComponent;
+        };
+
+        let mut context = SlashContext::new(
+            &self.http_client,
+            self.application_id,
+            &self.data,
+            &self.waiters,
+            &self.owners,
+            interaction,
+        );
+
+        debug!("Executing component handler [{}]", prefix);
+        ProcessResult::ComponentExecuted((hook.0)(&mut context, payload).await)
     }
 
     async fn try_autocomplete(&self, mut interaction: Interaction) -> ProcessResult<T, E> {
-        if let Some((name, argument, value)) = self.get_autocomplete_argument(&interaction) {
-            if let Some(fun) = &argument.autocomplete {
-                let context = AutocompleteContext::new(
-                    &self.http_client,
-                    &self.data,
-                    value,
-                    &mut interaction,
-                );
-                debug!("Command [{}] executing argument {} autocomplete function", name, argument.name);
-                let data = (fun.0)(context).await;
-
-                let _ = self
-                    .interaction_client()
-                    .create_response(
-                        interaction.id,
-                        &interaction.token,
-                        &InteractionResponse {
-                            kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
-                            data,
-                        },
-                    )
-                    .await;
+        if let Some((name, argument, fun, value)) = self.get_autocomplete_argument(&interaction) {
+            let responded = AtomicBool::new(false);
+            let context = AutocompleteContext::new(
+                &self.http_client,
+                &self.data,
+                value,
+                &mut interaction,
+                &responded,
+            );
+            debug!("Command [{}] executing argument {} autocomplete function", name, argument);
+            let data = fun(context).await;
 
+            if responded.load(Ordering::Acquire) {
                 return ProcessResult::Autocompleted;
             }
+
+            let _ = self
+                .interaction_client()
+                .create_response(
+                    interaction.id,
+                    &interaction.token,
+                    &InteractionResponse {
+                        kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+                        data,
+                    },
+                )
+                .await;
+
+            return ProcessResult::Autocompleted;
         }
 
         ProcessResult::CommandNotFound
@@ -174,7 +322,7 @@ where
     fn get_autocomplete_argument(
         &self,
         interaction: &Interaction,
-    ) -> Option<(&str, &CommandArgument<D, T, E>, Focused)> {
+    ) -> Option<(&'static str, &'static str, AutocompleteFn<D>, Focused)> {
         let data = extract!(interaction.data.as_ref().unwrap() => ApplicationCommand);
         if !data.options.is_empty() {
             let outer = data.options.get(0)?;
@@ -194,14 +342,26 @@ where
                 return None;
             };
 
-            let command = self.get_command(interaction)?;
-            let position = command
+            let command = self.find_command(interaction)?;
+            let argument = command
                 .arguments
                 .iter()
-                .position(|arg| arg.name == focused.name)?;
+                .find(|arg| arg.registers_option(&focused.name))?;
+
+            if !argument.autocomplete_enabled {
+                debug!("Command [{}] argument {} doesn't have autocomplete enabled", command.name, argument.name);
+                return None;
+            }
+
+            let Some(hook) = &argument.autocomplete else {
+                warn!("Command [{}] argument {} has autocomplete enabled but no handler", command.name, argument.name);
+                return None;
+            };
+
             return Some((
                 command.name,
-                command.arguments.get(position)?,
+                argument.name,
+                hook.0,
                 Focused {
                     input: input.clone(),
                     kind
@@ -222,11 +382,36 @@ where
     }
 
     /// Gets the command matching the given
-    /// [ApplicationCommand](ApplicationCommand),
+    /// [ApplicationCommand](ApplicationCommand), including the ones registered at runtime,
     /// returning `None` if no command matches the given interaction.
-    fn get_command(&self, interaction: &Interaction) -> Option<&Command<D, T, E>> {
+    fn find_command(&self, interaction: &Interaction) -> Option<CommandRef<'_, D, T, E>> {
+        if let Some(command) = self.get_static_command(interaction) {
+            return Some(CommandRef::Borrowed(command));
+        }
+
         let data = interaction.data.as_ref()?;
         let interaction_data = extract!(data => ApplicationCommand);
+        if self.get_next(&interaction_data.options).is_some() {
+            return None;
+        }
+
+        self.runtime_commands.read()
+            .get(&(interaction_data.kind, &*interaction_data.name))
+            .cloned()
+            .map(CommandRef::Shared)
+    }
+
+    /// Gets the command from the ones provided to the framework builder matching the given
+    /// [ApplicationCommand](ApplicationCommand).
+    fn get_static_command(&self, interaction: &Interaction) -> Option<&Command<D, T, E>> {
+        let data = interaction.data.as_ref()?;
+        let interaction_data = extract!(data => ApplicationCommand);
+        match interaction_data.kind {
+            CommandType::Message => return self.message_commands.get(&*interaction_data.name),
+            CommandType::User => return self.user_commands.get(&*interaction_data.name),
+            _ => ()
+        }
+
         if let Some(next) = self.get_next(&interaction_data.options) {
             let group = self.groups.get(&*interaction_data.name)?;
             match next.value.kind() {
@@ -245,9 +430,243 @@ where
                 }
                 _ => None,
             }
+        } else if let Some(command) = self.commands.get(&*interaction_data.name) {
+            Some(command)
         } else {
-            self.commands.get(&*interaction_data.name)
+            let group = self.groups.get(&*interaction_data.name)?;
+            let subcommand = group.default_subcommand?;
+            debug!("Group [{}] invoked without a subcommand, using [{}]", group.name, subcommand);
+            group.kind.as_simple()?.get(subcommand)
+        }
+    }
+
+    /// Gets the [group](GroupParent) containing the command of the given interaction, returning
+    /// `None` if the command isn't part of a group.
+    fn get_group(&self, interaction: &Interaction) -> Option<&GroupParent<D, T, E>> {
+        let data = interaction.data.as_ref()?;
+        let interaction_data = extract!(data => ApplicationCommand);
+        if interaction_data.kind != CommandType::ChatInput {
+            return None;
+        }
+
+        let group = self.groups.get(&*interaction_data.name)?;
+
+        // Groups invoked without a subcommand only run their default subcommand.
+        if self.get_next(&interaction_data.options).is_none() && group.default_subcommand.is_none() {
+            return None;
+        }
+
+        Some(group)
+    }
+
+    /// Returns whether a command with the given type and name was provided to the framework
+    /// builder.
+    fn contains_static_command(&self, kind: CommandType, name: &str) -> bool {
+        match kind {
+            CommandType::Message => self.message_commands.contains_key(name),
+            CommandType::User => self.user_commands.contains_key(name),
+            _ => self.commands.contains_key(name) || self.groups.contains_key(name)
+        }
+    }
+
+    /// Registers a command after the framework has been built, returning `false` if a command
+    /// with the same name and type is already registered.
+    ///
+    /// The command is only added to the framework, to also create it in discord, use
+    /// [register_and_create_command](Self::register_and_create_command).
+    pub fn register_command(&self, command: Command<D, T, E>) -> bool {
+        self.insert_runtime_command(command).is_some()
+    }
+
+    /// Registers a command after the framework has been built and creates it in discord, in the
+    /// given guild or globally if `None` is provided. Returns `false` if a command with the same
+    /// name and type is already registered.
+    ///
+    /// If the command fails to be created, it is removed from the framework.
+    pub async fn register_and_create_command(
+        &self,
+        command: Command<D, T, E>,
+        guild: Option<Id<GuildMarker>>
+    ) -> Result<bool, CreateCommandError> {
+        let Some(command) = self.insert_runtime_command(command) else {
+            return Ok(false);
+        };
+
+        if let Err(why) = self.create_command(&command, &self.interaction_client(), guild).await {
+            self.runtime_commands.write().remove(&(command.kind, command.name));
+            return Err(why);
+        }
+
+        Ok(true)
+    }
+
+    fn insert_runtime_command(&self, mut command: Command<D, T, E>) -> Option<Arc<Command<D, T, E>>> {
+        let key = (command.kind, command.name);
+        let mut commands = self.runtime_commands.write();
+
+        if self.contains_static_command(command.kind, command.name) || commands.contains_key(&key) {
+            return None;
+        }
+
+        debug!("Registering runtime command [{}]", command.name);
+        command.order_arguments();
+        let command = Arc::new(command);
+        commands.insert(key, Arc::clone(&command));
+        Some(command)
+    }
+
+    /// Removes the commands with the given name registered using
+    /// [register_command](Self::register_command), returning `false` if there wasn't any.
+    ///
+    /// Commands provided to the framework builder can't be removed. The command is only removed
+    /// from the framework, to also delete it from discord, use
+    /// [unregister_and_delete_command](Self::unregister_and_delete_command).
+    pub fn unregister_command(&self, name: &str) -> bool {
+        !self.remove_runtime_commands(name).is_empty()
+    }
+
+    /// Removes the commands with the given name registered using
+    /// [register_command](Self::register_command) and deletes them from discord, from the given
+    /// guild or globally if `None` is provided. Returns `false` if there wasn't any.
+    pub async fn unregister_and_delete_command(
+        &self,
+        name: &str,
+        guild: Option<Id<GuildMarker>>
+    ) -> Result<bool, CreateCommandError> {
+        let removed = self.remove_runtime_commands(name);
+        if removed.is_empty() {
+            return Ok(false);
+        }
+
+        let client = self.interaction_client();
+        let registered = match guild {
+            Some(guild) => client.guild_commands(guild).await?.models().await?,
+            None => client.global_commands().await?.models().await?
+        };
+
+        for command in registered {
+            let Some(id) = command.id else {
+                continue;
+            };
+
+            if command.name != name || !removed.contains(&command.kind) {
+                continue;
+            }
+
+            match guild {
+                Some(guild) => client.delete_guild_command(guild, id).await?,
+                None => client.delete_global_command(id).await?
+            };
+        }
+
+        Ok(true)
+    }
+
+    fn remove_runtime_commands(&self, name: &str) -> Vec<CommandType> {
+        let mut kinds = Vec::new();
+
+        self.runtime_commands.write().retain(|(kind, command), _| {
+            if *command == name {
+                debug!("Removing runtime command [{}]", name);
+                kinds.push(*kind);
+                false
+            } else {
+                true
+            }
+        });
+
+        kinds
+    }
+
+    /// Returns an iterator over all the commands provided to the framework builder, including
+    /// context menu commands and the subcommands of groups.
+    ///
+    /// Commands registered using [register_command](Self::register_command) are not included.
+    pub fn commands(&self) -> impl Iterator<Item = &Command<D, T, E>> {
+        self.simple_commands()
+            .chain(self.groups.values().flat_map(GroupParent::commands))
+    }
+
+    /// Gets a chat command provided to the framework builder by its name, subcommands are
+    /// retrieved using their full name, as shown in discord, like `config set`.
+    pub fn get_command(&self, name: &str) -> Option<&Command<D, T, E>> {
+        let mut parts = name.split_whitespace();
+        let first = parts.next()?;
+
+        let Some(group) = self.groups.get(first) else {
+            return match parts.next() {
+                Some(_) => None,
+                None => self.commands.get(first)
+            };
+        };
+
+        match (parts.next()?, parts.next(), parts.next()) {
+            (subcommand, None, _) => group.kind.as_simple()?.get(subcommand),
+            (subgroup, Some(subcommand), None) => group.kind.as_group()?
+                .get(subgroup)?
+                .subcommands
+                .get(subcommand),
+            _ => None
+        }
+    }
+
+    /// Validates the commands provided to the framework, returning the problems found, such as
+    /// arguments sharing the same name, required arguments declared after optional ones, commands
+    /// registering more than 25 options, names not following discord naming rules or descriptions
+    /// of commands, subcommands or groups exceeding 100 characters, which discord rejects. It also
+    /// checks the arguments given to [require_one_of](Command::require_one_of) are optional
+    /// arguments of the command.
+    ///
+    /// Arguments are checked on the options they register, so a variadic `tag` argument, which
+    /// registers `tag_1`, `tag_2`..., can't be declared next to an argument named `tag_1`.
+    ///
+    /// This is done when [building](FrameworkBuilder::build) the framework, logging the problems
+    /// found as warnings. Commands added through the builder have their required arguments moved
+    /// before the optional ones, so that check only fails for commands modified afterwards and
+    /// commands with several required variadic arguments, since only the first option of each one
+    /// is required.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        for command in self.simple_commands() {
+            let chat = command.kind == CommandType::ChatInput;
+            if let Err(error) = ValidationError::check_name(command.name, command.name, chat) {
+                errors.push(error);
+            }
+
+            if chat {
+                if let Err(error) = ValidationError::check_description(command.name, command.description) {
+                    errors.push(error);
+                }
+            }
+
+            command.validate_arguments(self, command.name, &mut errors);
+        }
+
+        for group in self.groups.values() {
+            group.validate_details(&mut errors);
+
+            for command in group.kind.as_simple().into_iter().flat_map(|commands| commands.values()) {
+                command.validate_arguments(self, &format!("{} {}", group.name, command.name), &mut errors);
+            }
+
+            for subgroup in group.kind.as_group().into_iter().flat_map(|groups| groups.values()) {
+                for command in subgroup.subcommands.values() {
+                    let path = format!("{} {} {}", group.name, subgroup.name, command.name);
+                    command.validate_arguments(self, &path, &mut errors);
+                }
+            }
         }
+
+        errors
+    }
+
+    /// Returns an iterator over all the commands that aren't part of a group, including context
+    /// menu commands.
+    fn simple_commands(&self) -> impl Iterator<Item = &Command<D, T, E>> {
+        self.commands.values()
+            .chain(self.message_commands.values())
+            .chain(self.user_commands.values())
     }
 
     /// Gets the next [option](CommandDataOption)
@@ -264,43 +683,166 @@ where
     }
 
     /// Executes the given [command](crate::command::Command) and the hooks.
-    async fn execute(&self, cmd: &Command<D, T, E>, interaction: Interaction) -> ExecutionResult<T, E> {
+    async fn execute(
+        &self,
+        cmd: &Command<D, T, E>,
+        group: Option<&GroupParent<D, T, E>>,
+        interaction: Interaction
+    ) -> ExecutionResult<T, E> {
+        #[cfg(feature = "metrics")]
+        let name = match &interaction.data {
+            Some(InteractionData::ApplicationCommand(data)) => command_path(data).join(" "),
+            _ => cmd.name.to_string()
+        };
+
         let mut context = SlashContext::new(
             &self.http_client,
             self.application_id,
             &self.data,
             &self.waiters,
+            &self.owners,
             interaction,
         );
+        context.ephemeral = cmd.ephemeral;
 
-        let execute = if let Some(before) = &self.before {
-            (before.0)(&mut context, cmd.name).await
-        } else {
-            true
+        let mut result = self.run_command(&mut context, cmd, group).await;
+        self.send_error_response(&context, cmd, &result).await;
+        result.acknowledged = context.is_acknowledged();
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::record(&name, &result);
+
+        if let Some(execution) = &self.execution {
+            #[allow(deprecated)]
+            let output = match result.output {
+                OutputLocation::Present(output) => {
+                    result.output = OutputLocation::TakenByExecutionHook;
+                    OutputLocation::Present(output)
+                },
+                OutputLocation::NotExecuted => OutputLocation::NotExecuted,
+                OutputLocation::TakenByAfterHook => OutputLocation::TakenByAfterHook,
+                OutputLocation::TakenByErrorHandler => OutputLocation::TakenByErrorHandler,
+                OutputLocation::TakenByExecutionHook => OutputLocation::TakenByExecutionHook
+            };
+
+            (execution.0)(&mut context, cmd.name, ExecutionResult {
+                state: result.state,
+                output,
+                elapsed: result.elapsed,
+                acknowledged: result.acknowledged,
+                reason: result.reason.clone()
+            }).await;
+        }
+
+        result
+    }
+
+    /// Sends the [error response](FrameworkBuilder::error_response) if the command raised an error
+    /// no error handler took care of, and the command didn't respond to the interaction. Deferred
+    /// interactions get their original response edited instead.
+    async fn send_error_response(
+        &self,
+        context: &SlashContext<'_, D>,
+        cmd: &Command<D, T, E>,
+        result: &ExecutionResult<T, E>
+    ) {
+        let Some(response) = &self.error_response else {
+            return;
         };
 
+        let unhandled = matches!(result.state, ExecutionState::CommandErrored | ExecutionState::CheckErrored)
+            && !matches!(result.output, OutputLocation::TakenByErrorHandler);
+
+        if !unhandled || context.is_responded() {
+            return;
+        }
+
+        debug!("Command [{}] raised an unhandled error, sending the error response", cmd.name);
+
+        if context.set_acknowledged() {
+            let request = context.update_response()
+                .content(response.content.as_deref())
+                .and_then(|request| request.embeds(response.embeds.as_deref()));
+
+            match request {
+                Ok(request) => if let Err(why) = request.await {
+                    warn!("Failed to send the error response of command [{}]: {}", cmd.name, why);
+                },
+                Err(why) => warn!("Invalid error response for command [{}]: {}", cmd.name, why)
+            }
+
+            return;
+        }
+
+        let response = context.interaction_client
+            .create_response(
+                context.interaction.id,
+                &context.interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::ChannelMessageWithSource,
+                    data: Some(response.clone())
+                }
+            )
+            .await;
+
+        match response {
+            Ok(_) => context.set_responded(),
+            Err(why) => {
+                context.unset_acknowledged();
+                warn!("Failed to send the error response of command [{}]: {}", cmd.name, why);
+            }
+        }
+    }
+
+    /// Runs the `before` hooks, the given command and the `after` hooks.
+    async fn run_command(
+        &self,
+        context: &mut SlashContext<'_, D>,
+        cmd: &Command<D, T, E>,
+        group: Option<&GroupParent<D, T, E>>
+    ) -> ExecutionResult<T, E> {
+        let mut execute = true;
+        for before in &self.before {
+            // The first hook returning false prevents the rest of them from executing.
+            if !(before.0)(context, cmd.name).await {
+                execute = false;
+                break;
+            }
+        }
+
+        if execute && self.enforce_permissions {
+            let required = cmd.required_permissions.unwrap_or_else(Permissions::empty)
+                | group.and_then(|group| group.required_permissions).unwrap_or_else(Permissions::empty);
+
+            if !required.is_empty() && !context.has_permissions(required) {
+                debug!("Command [{}] was used without the required permissions", cmd.name);
+                return ExecutionResult {
+                    state: ExecutionState::CheckFailed,
+                    output: OutputLocation::NotExecuted,
+                    elapsed: Duration::ZERO,
+                    acknowledged: false,
+                    reason: None
+                };
+            }
+        }
+
         if execute {
-            let mut result = cmd.execute(&mut context).await;
-
-            match (&self.after, result.state) {
-                // The after hook should not execute if any check returned false or a check errored.
-                (Some(after), 
-                ExecutionState::CommandFinished 
-                | ExecutionState::CommandErrored) => {
-                    // Set the output as taken, if it was already taken, we'll restore it to the previous state.
-                    let output = std::mem::replace(&mut result.output, OutputLocation::TakenByAfterHook);
-
-                    let output = if let OutputLocation::Present(return_value) = output {
-                        // If the output is not taken beforehand by the error handler, leave it as taken
-                        // by the after hook one.
-                        Some(return_value)
-                    } else {
-                        // If it was taken, return it to it's previous state.
-                        result.output = output;
-                        None
+            let result = cmd.execute_in(context, group, self.error_handler.as_ref()).await;
+
+            match result.state {
+                // The after hooks should not execute if any check returned false or a check errored.
+                ExecutionState::CommandFinished
+                | ExecutionState::CommandErrored if !self.after.is_empty() => {
+                    // The output is missing if it was taken beforehand by the error handler.
+                    let output = match &result.output {
+                        OutputLocation::Present(output) => Some(output),
+                        _ => None
                     };
 
-                    (after.0)(&mut context, cmd.name, output).await;
+                    // Every hook borrows the output, so all of them can observe it.
+                    for after in &self.after {
+                        (after.0)(context, cmd.name, output).await;
+                    }
                 },
                 _ => ()
             }
@@ -309,59 +851,228 @@ where
         } else {
             ExecutionResult {
                 state: ExecutionState::BeforeHookFailed,
-                output: OutputLocation::NotExecuted
+                output: OutputLocation::NotExecuted,
+                elapsed: Duration::ZERO,
+                acknowledged: false,
+                reason: None
             }
         }
     }
 
     /// Registers the commands provided to the framework in the specified guild.
+    ///
+    /// This registers all the global commands and the ones only available in the given guild.
     pub async fn register_guild_commands(
         &self,
         guild_id: Id<GuildMarker>,
     ) -> Result<Vec<TwilightCommand>, CreateCommandError> {
-        let mut commands = Vec::new();
+        self.register_in(Some(guild_id)).await
+    }
 
-        for cmd in self.commands.values() {
-            debug!("Registering command [{}]", cmd.name);
+    /// Removes every command registered in the given guild, overwriting the commands of the guild
+    /// with an empty list.
+    ///
+    /// This is meant for development and integration tests, where the commands registered in a
+    /// test guild accumulate across runs. Global commands are not affected, and nothing is removed
+    /// when running in [dry run](crate::builder::FrameworkBuilder::dry_run) mode.
+    pub async fn cleanup_guild_commands(&self, guild_id: Id<GuildMarker>) -> Result<(), CreateCommandError> {
+        if self.dry_run {
+            info!("Dry run, skipping removal of the commands of guild {}", guild_id);
+            return Ok(());
+        }
+
+        debug!("Removing all commands registered in guild {}", guild_id);
+        self.interaction_client()
+            .set_guild_commands(guild_id, &[])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Registers the commands provided to the framework globally.
+    ///
+    /// Commands only available in some guilds are not registered.
+    pub async fn register_global_commands(
+        &self,
+    ) -> Result<Vec<TwilightCommand>, CreateCommandError> {
+        self.register_in(None).await
+    }
+
+    /// Registers all the commands provided to the framework, commands only available in some
+    /// guilds are registered in each of their guilds, while the rest are registered globally.
+    pub async fn register_commands(&self) -> Result<Vec<TwilightCommand>, CreateCommandError> {
+        let mut commands = self.register_in(None).await?;
+        let client = self.interaction_client();
 
-            commands.push(cmd.create(&self, &self.interaction_client(), Some(guild_id)).await?);
+        for cmd in self.simple_commands() {
+            for guild in &cmd.guilds {
+                debug!("Registering command [{}] in guild {}", cmd.name, guild);
+                commands.push(self.create_command(cmd, &client, Some(*guild)).await?);
+            }
         }
 
         for group in self.groups.values() {
-            commands.push(group.create(&self, &self.interaction_client(), Some(guild_id)).await?);
+            for guild in &group.guilds {
+                commands.push(self.create_group(group, &client, Some(*guild)).await?);
+            }
         }
 
         Ok(commands)
     }
 
-    /// Registers the commands provided to the framework globally.
-    pub async fn register_global_commands(
+    /// Syncs the global commands provided to the framework with the ones registered in discord,
+    /// only creating, updating or deleting the commands that changed since they were registered.
+    ///
+    /// Commands only available in some guilds are not synced. When running in
+    /// [dry run](crate::builder::FrameworkBuilder::dry_run) mode, the registered commands are still
+    /// fetched to compute the summary, but nothing is created, updated nor deleted.
+    pub async fn sync_commands(&self) -> Result<SyncSummary, CreateCommandError> {
+        let client = self.interaction_client();
+        let mut registered = client.global_commands()
+            .with_localizations(true)
+            .await?
+            .models()
+            .await?;
+        let mut summary = SyncSummary::default();
+
+        for cmd in self.simple_commands().filter(|cmd| cmd.registered_in(None)) {
+            if summary.record(&CommandDefinition::from_command(self, cmd), &mut registered) {
+                debug!("Syncing command [{}]", cmd.name);
+                self.create_command(cmd, &client, None).await?;
+            }
+        }
+
+        for group in self.groups.values().filter(|group| group.registered_in(None)) {
+            if summary.record(&CommandDefinition::from_group(self, group), &mut registered) {
+                debug!("Syncing group [{}]", group.name);
+                self.create_group(group, &client, None).await?;
+            }
+        }
+
+        // The remaining commands are no longer provided to the framework, excluding the ones
+        // registered at runtime.
+        registered.retain(|command| {
+            !self.runtime_commands.read()
+                .keys()
+                .any(|(kind, name)| *kind == command.kind && *name == command.name)
+        });
+
+        for command in registered {
+            if let Some(id) = command.id {
+                if self.dry_run {
+                    info!("Dry run, skipping deletion of command [{}]", command.name);
+                } else {
+                    debug!("Deleting command [{}]", command.name);
+                    client.delete_global_command(id).await?;
+                }
+
+                summary.deleted += 1;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Creates the given command in discord, or only logs it if running in
+    /// [dry run](crate::builder::FrameworkBuilder::dry_run) mode.
+    async fn create_command(
+        &self,
+        cmd: &Command<D, T, E>,
+        client: &InteractionClient<'_>,
+        guild: Option<Id<GuildMarker>>
+    ) -> Result<TwilightCommand, CreateCommandError> {
+        if !self.dry_run {
+            return self.with_retries(cmd.name, || cmd.create(self, client, guild)).await;
+        }
+
+        let command = CommandDefinition::from_command(self, cmd).into_model(self.application_id, guild);
+        info!("Dry run, skipping registration of command [{}]: {:?}", cmd.name, command);
+        Ok(command)
+    }
+
+    /// Creates the given group in discord, or only logs it if running in
+    /// [dry run](crate::builder::FrameworkBuilder::dry_run) mode.
+    async fn create_group(
+        &self,
+        group: &GroupParent<D, T, E>,
+        client: &InteractionClient<'_>,
+        guild: Option<Id<GuildMarker>>
+    ) -> Result<TwilightCommand, CreateCommandError> {
+        if !self.dry_run {
+            return self.with_retries(group.name, || group.create(self, client, guild)).await;
+        }
+
+        let command = CommandDefinition::from_group(self, group).into_model(self.application_id, guild);
+        info!("Dry run, skipping registration of group [{}]: {:?}", group.name, command);
+        Ok(command)
+    }
+
+    /// Runs the given registration, retrying it with an exponential backoff while it fails
+    /// because of transient errors, up to the configured
+    /// [attempts](crate::builder::FrameworkBuilder::registration_attempts).
+    async fn with_retries<F, Fut>(&self, name: &str, create: F) -> Result<TwilightCommand, CreateCommandError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<TwilightCommand, CreateCommandError>>
+    {
+        let mut attempt = 1;
+
+        loop {
+            let error = match create().await {
+                Err(CreateCommandError::Http(error)) => error,
+                other => return other
+            };
+
+            match retry_delay(&error, attempt) {
+                Some(delay) if attempt < self.registration_attempts => {
+                    warn!("Registering command [{}] failed, retrying in {:?}: {}", name, delay, error);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                },
+                _ => return Err(CreateCommandError::Registration {
+                    command: name.to_string(),
+                    attempts: attempt,
+                    source: error
+                })
+            }
+        }
+    }
+
+    async fn register_in(
         &self,
+        guild: Option<Id<GuildMarker>>
     ) -> Result<Vec<TwilightCommand>, CreateCommandError> {
         let mut commands = Vec::new();
+        let client = self.interaction_client();
+
+        for cmd in self.simple_commands().filter(|cmd| cmd.registered_in(guild)) {
+            debug!("Registering command [{}]", cmd.name);
 
-        for cmd in self.commands.values() {
-            commands.push(cmd.create(&self, &self.interaction_client(), None).await?);
+            commands.push(self.create_command(cmd, &client, guild).await?);
         }
 
-        for group in self.groups.values() {
-            commands.push(group.create(&self, &self.interaction_client(), None).await?);
+        for group in self.groups.values().filter(|group| group.registered_in(guild)) {
+            commands.push(self.create_group(group, &client, guild).await?);
         }
 
         Ok(commands)
     }
 
     /// Creates a vector of Twilight [`Command`](twilight_model::application::command::Command) objects, to be used against Discord's bulk endpoint.
+    ///
+    /// Commands only available in some guilds are not included. Twilight commands can't represent
+    /// the [integration types](Command::integration_types) and [contexts](Command::contexts) of a
+    /// command, so they are missing from the returned commands, use
+    /// [export_commands](Self::export_commands) to get them.
     #[cfg(feature = "bulk")]
     pub fn twilight_commands(
         &self,
     ) -> Vec<TwilightCommand> {
-        use twilight_model::application::command::CommandType;
         use twilight_util::builder::command::CommandBuilder;
 
         let mut commands = Vec::new();
 
-        for cmd in self.commands.values() {
+        for cmd in self.simple_commands().filter(|cmd| cmd.registered_in(None)) {
             // only chat input commands can have a description
             // for other types of commands, the description is ignored, provided as an empty string
             let description = match cmd.kind {
@@ -373,8 +1084,8 @@ where
 
             // only chat input commands can have options and descriptions
             if cmd.kind == CommandType::ChatInput {
-                for i in &cmd.arguments {
-                    command = command.option(i.as_option(self, cmd));
+                for i in cmd.arguments.iter().flat_map(|a| a.as_options(self, cmd)) {
+                    command = command.option(i);
                 }
                 //if_some!(&cmd.localized_descriptions, |d| command = command.name_localizations(d));
                 if let Some(localizations) = cmd.localized_descriptions.get_localizations(self, cmd) {
@@ -383,6 +1094,7 @@ where
             }
 
             if_some!(cmd.required_permissions, |p| command = command.default_member_permissions(p));
+            command = command.dm_permission(!cmd.only_guilds).nsfw(cmd.nsfw);
             //if_some!(&cmd.localized_names, |n| command = command.name_localizations(n));
             if let Some(localizations) = cmd.localized_names.get_localizations(self, cmd) {
                 command = command.name_localizations(localizations);
@@ -392,7 +1104,7 @@ where
             commands.push(command.build());
         }
 
-        for group in self.groups.values() {
+        for group in self.groups.values().filter(|group| group.registered_in(None)) {
             let options = group.get_options(self);
             // groups are only supported by chat input
             let mut command = CommandBuilder::new(group.name, group.description, CommandType::ChatInput);
@@ -402,10 +1114,42 @@ where
             }
 
             if_some!(group.required_permissions, |p| command = command.default_member_permissions(p));
+            command = command.dm_permission(!group.only_guilds).nsfw(group.nsfw);
+            if_some!(&group.localized_names, |n| command = command.name_localizations(n.clone()));
+            if_some!(&group.localized_descriptions, |d| command = command.description_localizations(d.clone()));
 
             commands.push(command.build());
         }
 
         commands
     }
+
+    /// Serializes the [commands](Self::twilight_commands) of the framework into pretty printed
+    /// JSON, exactly as they are sent to discord's bulk endpoint, allowing to review or diff them.
+    ///
+    /// Commands only available in some guilds are not included. Unlike the twilight commands, the
+    /// exported ones include their integration types and contexts, and are sorted by their type
+    /// and name, so the same commands are always exported in the same order.
+    #[cfg(feature = "export")]
+    pub fn export_commands(&self) -> String {
+        let mut commands = self.twilight_commands()
+            .into_iter()
+            .map(|command| serde_json::to_value(command).expect("Commands are always serializable"))
+            .collect::<Vec<_>>();
+
+        // Twilight doesn't support integration types nor contexts, so they are added afterwards.
+        // Commands which aren't groups come first, in the same order.
+        let simple = self.simple_commands().filter(|cmd| cmd.registered_in(None));
+        for (command, cmd) in commands.iter_mut().zip(simple) {
+            crate::integration::insert_contexts(command, &cmd.integration_types, &cmd.contexts);
+        }
+
+        // The commands are stored in maps, so they are sorted to export them in a stable order.
+        commands.sort_by(|a, b| {
+            (a["type"].as_u64(), a["name"].as_str()).cmp(&(b["type"].as_u64(), b["name"].as_str()))
+        });
+
+        serde_json::to_string_pretty(&commands)
+            .expect("Commands are always serializable")
+    }
 }
@@ -3,14 +3,20 @@ use twilight_model::{id::{marker::GuildMarker, Id}, application::command::{Comma
 
 use crate::{
     command::{CommandMap, Command},
+    context::SlashContext,
+    error::ValidationError,
+    hook::{CheckHook, CheckResult, ErrorHandlerHook},
+    localizations::validate_options,
     twilight_exports::{Command as TwilightCommand, Permissions}, prelude::{CreateCommandError, Framework},
 };
 use std::collections::HashMap;
+use crate::hash::BuildNameHasher;
+use tracing::debug;
 
 /// A map of [parent groups](self::GroupParent).
-pub type GroupParentMap<D, T, E> = HashMap<&'static str, GroupParent<D, T, E>>;
+pub type GroupParentMap<D, T, E> = HashMap<&'static str, GroupParent<D, T, E>, BuildNameHasher>;
 /// A map of [command groups](self::CommandGroup).
-pub type CommandGroupMap<D, T, E> = HashMap<&'static str, CommandGroup<D, T, E>>;
+pub type CommandGroupMap<D, T, E> = HashMap<&'static str, CommandGroup<D, T, E>, BuildNameHasher>;
 
 /// Types a [group parent](self::GroupParent) can be.
 pub enum ParentType<D, T, E> {
@@ -18,27 +24,79 @@ pub enum ParentType<D, T, E> {
     Simple(CommandMap<D, T, E>),
     /// Group, the group has other groups inside of it.
     Group(CommandGroupMap<D, T, E>),
+    /// Mixed, the group has both subcommands and other groups inside of it.
+    Mixed(CommandMap<D, T, E>, CommandGroupMap<D, T, E>),
 }
 
 impl<D, T, E> ParentType<D, T, E> {
     /// Tries to get the [`map`](crate::command::CommandMap) of the given
     /// [parent type](self::ParentType), returning `Some` if the parent variant is
-    /// [`simple`](self::ParentType::Simple).
+    /// [`simple`](self::ParentType::Simple) or [`mixed`](self::ParentType::Mixed).
     pub fn as_simple(&self) -> Option<&CommandMap<D, T, E>> {
         match self {
-            Self::Simple(map) => Some(map),
+            Self::Simple(map) | Self::Mixed(map, _) => Some(map),
             _ => None,
         }
     }
 
     /// Tries to get the [`group`](self::CommandGroupMap) of the given [parent type](self::ParentType),
-    /// returning `Some` if the parent variant is a [`group`](self::ParentType::Group).
+    /// returning `Some` if the parent variant is a [`group`](self::ParentType::Group) or
+    /// [`mixed`](self::ParentType::Mixed).
     pub fn as_group(&self) -> Option<&CommandGroupMap<D, T, E>> {
         match self {
-            Self::Group(group) => Some(group),
+            Self::Group(group) | Self::Mixed(_, group) => Some(group),
             _ => None,
         }
     }
+
+    /// Mutable version of [as_simple](Self::as_simple).
+    pub(crate) fn as_simple_mut(&mut self) -> Option<&mut CommandMap<D, T, E>> {
+        match self {
+            Self::Simple(map) | Self::Mixed(map, _) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Mutable version of [as_group](Self::as_group).
+    pub(crate) fn as_group_mut(&mut self) -> Option<&mut CommandGroupMap<D, T, E>> {
+        match self {
+            Self::Group(group) | Self::Mixed(_, group) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Adds a subcommand, converting the parent into a [`mixed`](self::ParentType::Mixed) one if
+    /// it already has groups.
+    pub(crate) fn add_command(&mut self, command: Command<D, T, E>) {
+        *self = match std::mem::replace(self, Self::Simple(Default::default())) {
+            Self::Group(groups) if groups.is_empty() => Self::Simple(CommandMap::default()),
+            Self::Group(groups) => Self::Mixed(CommandMap::default(), groups),
+            other => other
+        };
+
+        let (Self::Simple(map) | Self::Mixed(map, _)) = self else {
+            unreachable!()
+        };
+
+        assert!(!map.contains_key(command.name), "{} already registered", command.name);
+        map.insert(command.name, command);
+    }
+
+    /// Adds a subcommand group, converting the parent into a [`mixed`](self::ParentType::Mixed)
+    /// one if it already has subcommands.
+    pub(crate) fn add_group(&mut self, group: CommandGroup<D, T, E>) {
+        *self = match std::mem::replace(self, Self::Group(Default::default())) {
+            Self::Simple(commands) => Self::Mixed(commands, CommandGroupMap::default()),
+            other => other
+        };
+
+        let (Self::Group(map) | Self::Mixed(_, map)) = self else {
+            unreachable!()
+        };
+
+        assert!(!map.contains_key(group.name), "{} already registered", group.name);
+        map.insert(group.name, group);
+    }
 }
 
 /// A parent of a group of sub commands, either a
@@ -53,12 +111,25 @@ pub struct GroupParent<D, T, E> {
     pub name: &'static str,
     /// The description of the upper command.
     pub description: &'static str,
+    /// The localized names of the upper command.
+    pub localized_names: Option<HashMap<String, String>>,
+    /// The localized descriptions of the upper command.
+    pub localized_descriptions: Option<HashMap<String, String>>,
     /// This parent group child commands.
     pub kind: ParentType<D, T, E>,
     /// The required permissions to execute commands inside this group
     pub required_permissions: Option<Permissions>,
     pub nsfw: bool,
-    pub only_guilds: bool
+    pub only_guilds: bool,
+    /// The checks executed before any command of this group, before the command ones.
+    pub checks: Vec<CheckHook<D, E>>,
+    /// The error handler used by the commands of this group that don't have their own.
+    pub error_handler: Option<ErrorHandlerHook<D, E>>,
+    /// The guilds this group is registered in, if empty, the group is registered globally.
+    pub guilds: Vec<Id<GuildMarker>>,
+    /// The subcommand executed when the group is invoked without any subcommand, which discord
+    /// doesn't allow, but can happen with malformed interactions.
+    pub default_subcommand: Option<&'static str>
 }
 
 /// A group of commands, referred by discord as `SubCommandGroup`.
@@ -71,11 +142,56 @@ pub struct CommandGroup<D, T, E> {
     pub name: &'static str,
     /// The description of this group.
     pub description: &'static str,
+    /// The localized names of this group.
+    pub localized_names: Option<HashMap<String, String>>,
+    /// The localized descriptions of this group.
+    pub localized_descriptions: Option<HashMap<String, String>>,
     /// The commands this group has as children.
     pub subcommands: CommandMap<D, T, E>,
 }
 
 impl<D, T, E> GroupParent<D, T, E> {
+    /// Returns an iterator over all the subcommands of this group, including the ones inside
+    /// subcommand groups.
+    pub fn commands(&self) -> impl Iterator<Item = &Command<D, T, E>> {
+        self.kind.as_simple()
+            .into_iter()
+            .flat_map(|commands| commands.values())
+            .chain(self.kind.as_group()
+                .into_iter()
+                .flat_map(|groups| groups.values())
+                .flat_map(|group| group.subcommands.values()))
+    }
+
+    /// Validates the names and descriptions of this group, its subcommand groups and subcommands,
+    /// adding the problems found to the given errors.
+    pub(crate) fn validate_details(&self, errors: &mut Vec<ValidationError>) {
+        let mut check = |path: String, name: &str, description: &str| {
+            if let Err(error) = ValidationError::check_name(&path, name, true) {
+                errors.push(error);
+            }
+
+            if let Err(error) = ValidationError::check_description(&path, description) {
+                errors.push(error);
+            }
+        };
+
+        check(self.name.to_string(), self.name, self.description);
+
+        for command in self.kind.as_simple().into_iter().flat_map(|commands| commands.values()) {
+            check(format!("{} {}", self.name, command.name), command.name, command.description);
+        }
+
+        for group in self.kind.as_group().into_iter().flat_map(|groups| groups.values()) {
+            check(format!("{} {}", self.name, group.name), group.name, group.description);
+
+            for command in group.subcommands.values() {
+                let path = format!("{} {} {}", self.name, group.name, command.name);
+                check(path, command.name, command.description);
+            }
+        }
+    }
+
     pub async fn create(
         &self,
         framework: &Framework<D, T, E>,
@@ -83,7 +199,14 @@ impl<D, T, E> GroupParent<D, T, E> {
         guild: Option<Id<GuildMarker>>
     ) -> Result<TwilightCommand, CreateCommandError>
     {
+        let mut errors = Vec::new();
+        self.validate_details(&mut errors);
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error.into());
+        }
+
         let options = self.get_options(framework);
+        validate_options(&options)?;
 
         let model = if let Some(id) = guild {
             let mut command = http.create_guild_command(id)
@@ -92,6 +215,8 @@ impl<D, T, E> GroupParent<D, T, E> {
                 .nsfw(self.nsfw);
 
             crate::if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            crate::if_some!(&self.localized_names, |n| command = command.name_localizations(n)?);
+            crate::if_some!(&self.localized_descriptions, |d| command = command.description_localizations(d)?);
 
             command.await?.model().await?
         } else {
@@ -102,6 +227,8 @@ impl<D, T, E> GroupParent<D, T, E> {
                 .dm_permission(!self.only_guilds);
 
             crate::if_some!(self.required_permissions, |p| command = command.default_member_permissions(p));
+            crate::if_some!(&self.localized_names, |n| command = command.name_localizations(n)?);
+            crate::if_some!(&self.localized_descriptions, |d| command = command.description_localizations(d)?);
 
             command.await?.model().await?
         };
@@ -109,10 +236,44 @@ impl<D, T, E> GroupParent<D, T, E> {
         Ok(model)
     }
 
+    /// Returns whether the group should be registered in the given guild, or globally if `None`
+    /// is provided.
+    pub fn registered_in(&self, guild: Option<Id<GuildMarker>>) -> bool {
+        match guild {
+            Some(id) => self.guilds.is_empty() || self.guilds.contains(&id),
+            None => self.guilds.is_empty()
+        }
+    }
+
+    /// Runs the checks of this group, returning the result of the first one that failed, if any.
+    pub async fn run_checks<'cx, 'data: 'cx>(&self, context: &'cx mut SlashContext<'data, D>) -> Result<CheckResult, E> {
+        if context.bypass_checks {
+            debug!("Group [{}] checks bypassed", self.name);
+            return Ok(CheckResult::Passed);
+        }
+
+        debug!("Running group [{}] checks", self.name);
+        for check in &self.checks {
+            let result = (check.0)(context).await?;
+            if !result.is_passed() {
+                debug!("Group [{}] check failed", self.name);
+                return Ok(result);
+            }
+        }
+        debug!("All group [{}] checks passed", self.name);
+        Ok(CheckResult::Passed)
+    }
+
     pub fn get_options(&self, f: &Framework<D, T, E>) -> Vec<CommandOption> {
-        if let ParentType::Group(groups) = &self.kind {
-            let mut subgroups = Vec::new();
+        let mut options = Vec::new();
+
+        if let Some(commands) = self.kind.as_simple() {
+            for sub in commands.values() {
+                options.push(self.create_subcommand(f, sub));
+            }
+        }
 
+        if let Some(groups) = self.kind.as_group() {
             for group in groups.values() {
                 let mut subcommands = Vec::new();
 
@@ -120,7 +281,7 @@ impl<D, T, E> GroupParent<D, T, E> {
                     subcommands.push(self.create_subcommand(f, cmd));
                 }
 
-                subgroups.push(CommandOption {
+                options.push(CommandOption {
                     kind: CommandOptionType::SubCommandGroup,
                     name: group.name.to_string(),
                     description: group.description.to_string(),
@@ -129,26 +290,17 @@ impl<D, T, E> GroupParent<D, T, E> {
                     choices: None,
                     required: None,
                     channel_types: None,
-                    description_localizations: None,
+                    description_localizations: group.localized_descriptions.clone(),
                     max_length: None,
                     max_value: None,
                     min_length: None,
                     min_value: None,
-                    name_localizations: None,
+                    name_localizations: group.localized_names.clone(),
                 });
             }
-
-            subgroups
-        } else if let ParentType::Simple(commands) = &self.kind {
-            let mut subcommands = Vec::new();
-            for sub in commands.values() {
-                subcommands.push(self.create_subcommand(f, sub));
-            }
-
-            subcommands
-        } else {
-            unreachable!()
         }
+
+        options
     }
 
     /// Creates a subcommand at the given scope.
@@ -157,7 +309,7 @@ impl<D, T, E> GroupParent<D, T, E> {
             kind: CommandOptionType::SubCommand,
             name: cmd.name.to_string(),
             description: cmd.description.to_string(),
-            options: Some(cmd.arguments.iter().map(|a| a.as_option(f, cmd)).collect()),
+            options: Some(cmd.arguments.iter().flat_map(|a| a.as_options(f, cmd)).collect()),
             autocomplete: None,
             choices: None,
             required: None,
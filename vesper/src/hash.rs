@@ -0,0 +1,36 @@
+//! The hasher used by the maps of commands, groups and component handlers.
+
+use std::hash::{BuildHasherDefault, Hasher};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// A [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hasher,
+/// which is faster than the default one for short keys like command names.
+///
+/// This hasher is not resistant to collision attacks, which isn't a problem for the framework
+/// maps since their keys are only provided by the developer.
+#[derive(Debug, Clone, Copy)]
+pub struct NameHasher(u64);
+
+impl Default for NameHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for NameHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// The [BuildHasher](std::hash::BuildHasher) of the [name hasher](NameHasher).
+pub type BuildNameHasher = BuildHasherDefault<NameHasher>;
@@ -1,3 +1,4 @@
+use crate::command::ExecutionResult;
 use crate::context::AutocompleteContext;
 use crate::{
     context::SlashContext, twilight_exports::InteractionResponseData,
@@ -16,14 +17,15 @@ pub struct BeforeHook<D>(pub BeforeFn<D>);
 
 /// A pointer to a function used by [after hook](AfterHook).
 pub(crate) type AfterFn<D, T, E> =
-    for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>, &'cx str, Option<Result<T, E>>) -> BoxFuture<'cx, ()>;
+    for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>, &'cx str, Option<&'cx Result<T, E>>) -> BoxFuture<'cx, ()>;
 
 /// A hook executed after a command execution.
 ///
 /// The function must have as parameters a [slash context] reference, a `&str` which contains
-/// the name of the command, and an `Option<Result<T, E>>`.
+/// the name of the command, and an `Option<&Result<T, E>>`.
 ///
-/// The result contained in the option must be the same as your command's output.
+/// The result contained in the option must be the same as your command's output. It is borrowed,
+/// so every after hook can observe it.
 ///
 /// Note that it will be missing only if the command had an error and an error handler was set
 /// to handle the error.
@@ -31,6 +33,46 @@ pub(crate) type AfterFn<D, T, E> =
 /// [slash context]: SlashContext
 pub struct AfterHook<D, T, E>(pub AfterFn<D, T, E>);
 
+/// A pointer to a function used by [execution hook](ExecutionHook).
+pub(crate) type ExecutionFn<D, T, E> =
+    for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>, &'cx str, ExecutionResult<T, E>) -> BoxFuture<'cx, ()>;
+
+/// A hook executed once the framework finishes processing a command, whatever the outcome was.
+///
+/// Unlike the [after hook](AfterHook), this one is also executed when the command did not run
+/// because the `before` hook or a check failed, or because the command was on cooldown.
+///
+/// The function must have as parameters a [slash context] reference, a `&str` which contains
+/// the name of the command, and the [execution result](ExecutionResult) of the command.
+///
+/// [slash context]: SlashContext
+pub struct ExecutionHook<D, T, E>(pub ExecutionFn<D, T, E>);
+
+/// A pointer to a function used by [unknown command hook](UnknownCommandHook).
+pub(crate) type UnknownCommandFn<D> = for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>, &'cx str) -> BoxFuture<'cx, ()>;
+
+/// A hook executed when discord sends a command which isn't provided to the framework, for
+/// example, a global command registered by a previous version of the bot.
+///
+/// The function must have as parameters a [slash context] reference and a `&str` which contains
+/// the full name of the command, like `config set`.
+///
+/// [slash context]: SlashContext
+pub struct UnknownCommandHook<D>(pub UnknownCommandFn<D>);
+
+/// A pointer to a function used by [component hook](ComponentHook).
+pub(crate) type ComponentFn<D, T, E> =
+    for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>, &'cx str) -> BoxFuture<'cx, Result<T, E>>;
+
+/// A hook used to handle message component interactions whose custom id matches the prefix the
+/// hook was registered with.
+///
+/// The function must have as parameters a [slash context] reference and a `&str` which contains
+/// the payload of the custom id, this is, everything after the prefix and the separator.
+///
+/// [slash context]: SlashContext
+pub struct ComponentHook<D, T, E>(pub ComponentFn<D, T, E>);
+
 /// A pointer to a function used by [autocomplete hook](AutocompleteHook).
 pub(crate) type AutocompleteFn<D> =
     for<'data> fn(AutocompleteContext<'data, D>) -> BoxFuture<'data, Option<InteractionResponseData>>;
@@ -40,8 +82,43 @@ pub(crate) type AutocompleteFn<D> =
 /// The function must have as parameter a single [autocomplete context](AutocompleteContext).
 pub struct AutocompleteHook<D>(pub AutocompleteFn<D>);
 
+/// The outcome of a [check](CheckHook).
+///
+/// Checks can return either a `bool` or a check result, returning `false` is the same as
+/// returning [Failed](CheckResult::Failed) without a reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    /// The check passed, so the command can be executed.
+    Passed,
+    /// The check failed and the command won't be executed, optionally containing the reason,
+    /// which is given to the hooks through [ExecutionResult::reason].
+    Failed(Option<String>)
+}
+
+impl CheckResult {
+    /// Creates a failed result with the given reason.
+    pub fn failed(reason: impl Into<String>) -> Self {
+        Self::Failed(Some(reason.into()))
+    }
+
+    /// Returns whether the check passed.
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Self::Passed)
+    }
+}
+
+impl From<bool> for CheckResult {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Passed
+        } else {
+            Self::Failed(None)
+        }
+    }
+}
+
 /// A pointer to a function used by the [check hook](CheckHook).
-pub(crate) type CheckFn<D, E> = for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>) -> BoxFuture<'cx, Result<bool, E>>;
+pub(crate) type CheckFn<D, E> = for<'cx, 'data> fn(&'cx mut SlashContext<'data, D>) -> BoxFuture<'cx, Result<CheckResult, E>>;
 
 /// A hook that can be used to determine if a command should execute or not depending
 /// on the given function.
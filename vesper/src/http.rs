@@ -0,0 +1,134 @@
+//! Utilities used to receive interactions through an HTTP endpoint instead of the gateway.
+//!
+//! Discord signs every request sent to the interactions endpoint, the signature must be verified
+//! before doing anything with the request, since discord rejects endpoints accepting forged
+//! requests. [handle_request] verifies the signature, answers pings and forwards the rest of the
+//! interactions to the [framework](Framework).
+//!
+//! # Examples
+//!
+//! A minimal [axum](https://docs.rs/axum) handler:
+//!
+//! ```rust,ignore
+//! use axum::{body::Bytes, extract::State, http::{HeaderMap, StatusCode}, response::IntoResponse, Json};
+//! use std::sync::Arc;
+//! use vesper::{http::{handle_request, WebhookResponse, SIGNATURE_HEADER, TIMESTAMP_HEADER}, prelude::*};
+//!
+//! const PUBLIC_KEY: &str = "<your application public key>";
+//!
+//! async fn interactions(
+//!     State(framework): State<Arc<Framework<()>>>,
+//!     headers: HeaderMap,
+//!     body: Bytes
+//! ) -> impl IntoResponse {
+//!     let header = |name| headers.get(name).and_then(|value| value.to_str().ok()).unwrap_or_default();
+//!
+//!     match handle_request(&framework, PUBLIC_KEY, header(SIGNATURE_HEADER), header(TIMESTAMP_HEADER), &body).await {
+//!         Ok(WebhookResponse::Pong(pong)) => Json(pong).into_response(),
+//!         // Commands respond to the interaction using discord's API.
+//!         Ok(WebhookResponse::Processed(_)) => StatusCode::NO_CONTENT.into_response(),
+//!         Err(_) => StatusCode::UNAUTHORIZED.into_response()
+//!     }
+//! }
+//! ```
+
+use crate::{
+    framework::{Framework, ProcessResult},
+    parse::ParseError,
+    twilight_exports::{Interaction, InteractionResponse, InteractionResponseType, InteractionType},
+};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use thiserror::Error;
+
+/// The header containing the signature of the request.
+pub const SIGNATURE_HEADER: &str = "X-Signature-Ed25519";
+/// The header containing the timestamp of the request, which is part of the signed message.
+pub const TIMESTAMP_HEADER: &str = "X-Signature-Timestamp";
+
+/// Errors returned when handling requests received from discord.
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    /// The provided public key is not a valid hex encoded ed25519 key.
+    #[error("Invalid public key")]
+    InvalidPublicKey,
+    /// The signature of the request is missing or doesn't match its content.
+    #[error("Invalid request signature")]
+    InvalidSignature,
+    /// The body of the request is not a valid interaction.
+    #[error(transparent)]
+    Deserialize(#[from] serde_json::Error)
+}
+
+/// The outcome of [handling](handle_request) a request.
+#[allow(clippy::large_enum_variant)]
+pub enum WebhookResponse<T, E> {
+    /// The request was a ping, the contained response must be sent back as the JSON body of the
+    /// HTTP response.
+    Pong(InteractionResponse),
+    /// The interaction was processed by the framework.
+    Processed(ProcessResult<T, E>)
+}
+
+/// Decodes the given hex string into a byte array of the given length.
+fn decode_hex<const N: usize>(input: &str) -> Option<[u8; N]> {
+    // `from_str_radix` accepts a leading sign, so each byte is checked to be a hex digit first.
+    if input.len() != N * 2 || !input.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let mut output = [0; N];
+    for (index, byte) in output.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&input[index * 2..index * 2 + 2], 16).ok()?;
+    }
+
+    Some(output)
+}
+
+/// Verifies the ed25519 signature of a request received from discord, using the public key of
+/// the application, the values of the [signature](SIGNATURE_HEADER) and
+/// [timestamp](TIMESTAMP_HEADER) headers and the raw body of the request.
+pub fn verify_signature(
+    public_key: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8]
+) -> Result<(), WebhookError> {
+    let public_key = decode_hex::<32>(public_key).ok_or(WebhookError::InvalidPublicKey)?;
+    let signature = decode_hex::<64>(signature).ok_or(WebhookError::InvalidSignature)?;
+
+    let mut message = Vec::with_capacity(timestamp.len() + body.len());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(&message, &signature)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Handles a request received from discord, verifying its signature before parsing the body.
+///
+/// Pings are answered with a pong, which must be sent back as the HTTP response, while the rest
+/// of interactions are forwarded to [handle_interaction](Framework::handle_interaction).
+pub async fn handle_request<D, T, E>(
+    framework: &Framework<D, T, E>,
+    public_key: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8]
+) -> Result<WebhookResponse<T, E>, WebhookError>
+where
+    E: From<ParseError>
+{
+    verify_signature(public_key, signature, timestamp, body)?;
+
+    let interaction = serde_json::from_slice::<Interaction>(body)?;
+
+    if interaction.kind == InteractionType::Ping {
+        return Ok(WebhookResponse::Pong(InteractionResponse {
+            kind: InteractionResponseType::Pong,
+            data: None
+        }));
+    }
+
+    Ok(WebhookResponse::Processed(framework.handle_interaction(interaction).await))
+}
@@ -0,0 +1,118 @@
+//! Types used to choose where a command can be installed and where it can be used.
+//!
+//! Discord allows applications to be installed to users as well as to guilds, and commands can
+//! choose which installations and which contexts make them available. Since twilight doesn't
+//! support those fields yet, commands using them are registered with a raw request.
+
+use crate::{
+    framework::Framework,
+    prelude::CreateCommandError,
+    sync::CommandDefinition,
+    twilight_exports::{Command as TwilightCommand, GuildMarker, Id},
+};
+use serde_json::{json, Value};
+use twilight_http::{request::Request, routing::Route};
+
+/// The installation contexts a command is available in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegrationType {
+    /// The command is available when the application is installed to a guild.
+    GuildInstall,
+    /// The command is available when the application is installed to a user.
+    UserInstall
+}
+
+impl IntegrationType {
+    /// Returns the value discord uses to represent this integration type.
+    pub fn value(self) -> u8 {
+        match self {
+            Self::GuildInstall => 0,
+            Self::UserInstall => 1
+        }
+    }
+}
+
+/// The contexts where a command can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InteractionContext {
+    /// The command can be used in guilds.
+    Guild,
+    /// The command can be used in the direct messages of the bot.
+    BotDm,
+    /// The command can be used in group direct messages and direct messages other than the ones
+    /// of the bot, this requires the application to be installed to the user.
+    PrivateChannel
+}
+
+impl InteractionContext {
+    /// Returns the value discord uses to represent this context.
+    pub fn value(self) -> u8 {
+        match self {
+            Self::Guild => 0,
+            Self::BotDm => 1,
+            Self::PrivateChannel => 2
+        }
+    }
+}
+
+/// Registers the given command definition, including the given integration types and contexts,
+/// which are omitted when empty.
+pub(crate) async fn create_command<D, T, E>(
+    framework: &Framework<D, T, E>,
+    definition: CommandDefinition,
+    guild: Option<Id<GuildMarker>>,
+    integration_types: &[IntegrationType],
+    contexts: &[InteractionContext]
+) -> Result<TwilightCommand, CreateCommandError> {
+    let model = definition.into_model(framework.application_id, guild);
+
+    let mut payload = json!({
+        "name": model.name,
+        "type": u8::from(model.kind),
+        "description": model.description,
+        "options": model.options,
+        "name_localizations": model.name_localizations,
+        "description_localizations": model.description_localizations,
+        "default_member_permissions": model.default_member_permissions,
+        "nsfw": model.nsfw
+    });
+
+    // Guild commands can't be used in direct messages, so discord doesn't accept the field.
+    if guild.is_none() {
+        payload["dm_permission"] = json!(model.dm_permission);
+    }
+
+    insert_contexts(&mut payload, integration_types, contexts);
+
+    let application_id = framework.application_id.get();
+    let route = match guild {
+        Some(guild) => Route::CreateGuildCommand { application_id, guild_id: guild.get() },
+        None => Route::CreateGlobalCommand { application_id }
+    };
+
+    let request = Request::builder(&route)
+        .json(&payload)?
+        .build();
+
+    Ok(framework.http_client.inner().request::<TwilightCommand>(request).await?.model().await?)
+}
+
+/// Adds the given integration types and contexts to the JSON payload of a command, omitting
+/// them when empty.
+pub(crate) fn insert_contexts(
+    payload: &mut Value,
+    integration_types: &[IntegrationType],
+    contexts: &[InteractionContext]
+) {
+    if !integration_types.is_empty() {
+        payload["integration_types"] = integration_types.iter()
+            .map(|kind| Value::from(kind.value()))
+            .collect();
+    }
+
+    if !contexts.is_empty() {
+        payload["contexts"] = contexts.iter()
+            .map(|context| Value::from(context.value()))
+            .collect();
+    }
+}
@@ -1,11 +1,18 @@
 use crate::builder::WrappedClient;
 use crate::context::SlashContext;
 use crate::parse::{Parse, ParseError};
-use crate::twilight_exports::{InteractionData, CommandDataOption, CommandOptionType, CommandOptionValue, CommandInteractionDataResolved};
+use crate::parse_impl::out_of_range;
+use crate::twilight_exports::{InteractionData, CommandData, CommandDataOption, CommandOptionType, CommandOptionValue, CommandInteractionDataResolved};
+use smallvec::SmallVec;
+use twilight_validate::command::OPTIONS_LIMIT;
+
+/// The options provided to a command, stored inline since a command can't have more than
+/// [OPTIONS_LIMIT] options, so parsing them doesn't allocate.
+pub type Options<'a> = SmallVec<[&'a CommandDataOption; OPTIONS_LIMIT]>;
 
 /// An iterator used to iterate through slash command options.
 pub struct DataIterator<'a, D> {
-    src: Vec<&'a CommandDataOption>,
+    src: Options<'a>,
     resolved: &'a mut Option<CommandInteractionDataResolved>,
     http: &'a WrappedClient,
     data: &'a D
@@ -34,39 +41,17 @@ impl<'a, D: 'a> DataIterator<'a, D> {
     where
         F: Fn(&CommandDataOption) -> bool,
     {
-        let i = {
-            let mut idx = 0;
-            let mut found = false;
-
-            while idx < self.src.len() && !found {
-                if predicate(self.src[idx]) {
-                    found = true;
-                }
-
-                if !found {
-                    idx += 1;
-                }
-            }
-
-            if found {
-                Some(idx)
-            } else {
-                None
-            }
-        };
-
-        if let Some(i) = i {
-            Some(self.src.remove(i))
-        } else {
-            None
-        }
+        let index = self.src.iter().position(|option| predicate(option))?;
+        Some(self.src.remove(index))
     }
 
     pub fn resolved(&mut self) -> Option<&mut CommandInteractionDataResolved> {
         self.resolved.as_mut()
     }
 
-    fn get_data(options: &Vec<CommandDataOption>) -> Vec<&CommandDataOption> {
+    /// Gets the options provided to the executed command, skipping subcommands and subcommand
+    /// groups.
+    pub(crate) fn get_data(options: &Vec<CommandDataOption>) -> Options<'_> {
         if let Some(index) = options.iter().position(|item| {
             item.value.kind() == CommandOptionType::SubCommand
                 || item.value.kind() == CommandOptionType::SubCommandGroup
@@ -93,9 +78,22 @@ where
     where
         T: Parse<D>
     {
+        self.named_parse_as(name, <T as Parse<D>>::required()).await
+    }
+
+    /// Parses the option with the given name, overriding whether the option is required instead
+    /// of using [required](Parse::required).
+    pub async fn named_parse_as<T>(&mut self, name: &str, required: bool) -> Result<T, ParseError>
+    where
+        T: Parse<D>
+    {
+        if let Some(max) = <T as Parse<D>>::variadic() {
+            return self.variadic_parse(name, max, required).await;
+        }
+
         let value = self.get(|s| s.name == name);
-        if value.is_none() && <T as Parse<D>>::required() {
-            Err(ParseError::StructureMismatch(format!("{} not found", name)).into())
+        if value.is_none() && required {
+            Err(ParseError::MissingArgument { argument_name: name.to_string() })
         } else {
             Ok(T::parse(
                 self.http,
@@ -104,17 +102,86 @@ where
                 self.resolved())
                 .await
                 .map_err(|mut err| {
-                    if let ParseError::Parsing { argument_name, .. } = &mut err {
-                        *argument_name = name.to_string();
-                    }
+                    err.set_argument_name(name);
                     err
                 })?)
         }
     }
+
+    /// Checks the length of the string options registered by the argument with the given name
+    /// are within the given bounds, variadic arguments check each of their `<name>_N` options.
+    pub fn check_length<T>(&self, name: &str, min: Option<u16>, max: Option<u16>) -> Result<(), ParseError>
+    where
+        T: Parse<D>
+    {
+        let variadic = <T as Parse<D>>::variadic();
+        let registered = |option: &str| match variadic {
+            Some(max) => option.strip_prefix(name)
+                .and_then(|suffix| suffix.strip_prefix('_'))
+                .and_then(|index| index.parse::<usize>().ok())
+                .is_some_and(|index| (1..=max).contains(&index)),
+            None => option == name
+        };
+
+        for option in self.src.iter().filter(|option| registered(&option.name)) {
+            let CommandOptionValue::String(value) = &option.value else {
+                continue;
+            };
+
+            let length = value.chars().count();
+            let expected = match (min.map(usize::from), max.map(usize::from)) {
+                (Some(min), Some(max)) if !(min..=max).contains(&length) => format!("between {} and {}", min, max),
+                (Some(min), None) if length < min => format!("at least {}", min),
+                (None, Some(max)) if length > max => format!("at most {}", max),
+                _ => continue
+            };
+
+            let mut error = out_of_range(
+                "String",
+                &format!("Input length {} out of range, expected a length {}", length, expected)
+            );
+            error.set_argument_name(&option.name);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Collects all the options registered by a variadic argument, named `<name>_1`, `<name>_2`...
+    async fn variadic_parse<T>(&mut self, name: &str, max: usize, required: bool) -> Result<T, ParseError>
+    where
+        T: Parse<D>
+    {
+        let mut values = Vec::with_capacity(max);
+        for index in 1..=max {
+            // Compare the option names in place, so no name is allocated for each index.
+            let value = self.get(|s| {
+                s.name.strip_prefix(name)
+                    .and_then(|suffix| suffix.strip_prefix('_'))
+                    .and_then(|suffix| suffix.parse::<usize>().ok())
+                    == Some(index)
+            });
+
+            if let Some(value) = value {
+                values.push(&value.value);
+            }
+        }
+
+        if values.is_empty() && required {
+            return Err(ParseError::MissingArgument { argument_name: name.to_string() });
+        }
+
+        T::parse_variadic(self.http, self.data, values, self.resolved())
+            .await
+            .map_err(|mut err| {
+                err.set_argument_name(name);
+                err
+            })
+    }
 }
 
 impl<'a, D> std::ops::Deref for DataIterator<'a, D> {
-    type Target = Vec<&'a CommandDataOption>;
+    type Target = Options<'a>;
 
     fn deref(&self) -> &Self::Target {
         &self.src
@@ -126,3 +193,80 @@ impl<D> std::ops::DerefMut for DataIterator<'_, D> {
         &mut self.src
     }
 }
+
+/// An iterator over the options of a command, flattening subcommands and subcommand groups.
+///
+/// Each option which isn't a subcommand nor a subcommand group is yielded along with the path of
+/// names traversed to reach it, starting with the name of the command, so the option `address`
+/// of `/config email set` is yielded with the path `["config", "email", "set"]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::iter::FlattenedOptions;
+/// use vesper::twilight_exports::CommandData;
+///
+/// fn log_options(data: &CommandData) {
+///     for (path, option) in FlattenedOptions::new(data) {
+///         println!("{}: {} = {:?}", path.join("/"), option.name, option.value);
+///     }
+/// }
+/// ```
+pub struct FlattenedOptions<'a> {
+    path: Vec<&'a str>,
+    stack: Vec<std::slice::Iter<'a, CommandDataOption>>
+}
+
+impl<'a> FlattenedOptions<'a> {
+    /// Creates a new iterator over the options of the given command.
+    pub fn new(data: &'a CommandData) -> Self {
+        Self {
+            path: vec![data.name.as_str()],
+            stack: vec![data.options.iter()]
+        }
+    }
+}
+
+impl<'a> Iterator for FlattenedOptions<'a> {
+    type Item = (Vec<&'a str>, &'a CommandDataOption);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(option) = self.stack.last_mut()?.next() else {
+                // All the options of the current level were yielded, go back to the previous one.
+                self.stack.pop();
+                self.path.pop();
+                continue;
+            };
+
+            match &option.value {
+                CommandOptionValue::SubCommand(options)
+                | CommandOptionValue::SubCommandGroup(options) => {
+                    self.path.push(option.name.as_str());
+                    self.stack.push(options.iter());
+                },
+                _ => return Some((self.path.clone(), option))
+            }
+        }
+    }
+}
+
+/// Returns the full path of the command executed, including the subcommand group and subcommand
+/// used, like `["config", "email", "set"]`.
+pub fn command_path(data: &CommandData) -> Vec<&str> {
+    let mut path = vec![data.name.as_str()];
+    let mut options = &data.options;
+
+    while let Some(option) = options.first() {
+        match &option.value {
+            CommandOptionValue::SubCommand(next)
+            | CommandOptionValue::SubCommandGroup(next) => {
+                path.push(option.name.as_str());
+                options = next;
+            },
+            _ => break
+        }
+    }
+
+    path
+}
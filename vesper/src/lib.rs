@@ -4,18 +4,36 @@ mod parse_impl;
 
 pub mod argument;
 pub mod builder;
+pub mod checks;
 pub mod command;
+pub mod components;
 pub mod context;
+pub mod cooldown;
+pub mod embeds;
 pub mod error;
+pub mod extensions;
 pub mod framework;
 pub mod group;
+pub mod hash;
 pub mod hook;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod integration;
 pub mod iter;
 pub mod localizations;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod modal;
+pub mod pagination;
 pub mod parse;
 pub mod parsers;
 pub mod range;
+pub mod response;
+pub mod router;
+pub mod sync;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod variadic;
 pub mod wait;
 
 // Items used to extract generics from functions, not public API.
@@ -31,12 +49,16 @@ pub mod prelude {
     pub use crate::{
         builder::{FrameworkBuilder, WrappedClient},
         context::{AutocompleteContext, Focused, SlashContext},
+        cooldown::{Cooldown, CooldownBucket},
         error::*,
+        extensions::Extensions,
         framework::{DefaultCommandResult, Framework},
+        hook::CheckResult,
         modal::*,
-        parse::{Parse, ParseError},
+        parse::{Parse, ParseError, ParseTarget},
         parsers,
         range::Range,
+        variadic::{Unique, Variadic},
     };
     pub use async_trait::async_trait;
     pub use vesper_macros::*;
@@ -46,22 +68,31 @@ pub mod prelude {
 pub mod twilight_exports {
     pub use twilight_http::{
         client::{Client, InteractionClient},
-        request::application::interaction::UpdateResponse,
+        request::application::interaction::{
+            CreateFollowup, DeleteFollowup, DeleteResponse, UpdateFollowup, UpdateResponse
+        },
         response::DeserializeBodyError
     };
     pub use twilight_model::{
         application::{
             command::{Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType, CommandType},
             interaction::{
-                application_command::{CommandData, CommandDataOption, CommandOptionValue, CommandInteractionDataResolved},
+                application_command::{CommandData, CommandDataOption, CommandOptionValue, CommandInteractionDataResolved, InteractionMember},
                 modal::ModalInteractionData,
                 message_component::MessageComponentInteractionData,
                 Interaction, InteractionData, InteractionType,
             },
         },
-        channel::{Message, message::{Component, component::{ActionRow, TextInput, TextInputStyle}}},
+        channel::{
+            Message,
+            message::{
+                Component,
+                ReactionType,
+                component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, TextInput, TextInputStyle}
+            }
+        },
         gateway::payload::incoming::InteractionCreate,
-        guild::Permissions,
+        guild::{PartialMember, Permissions},
         http::interaction::{
             InteractionResponse, InteractionResponseData, InteractionResponseType,
         },
@@ -72,5 +103,6 @@ pub mod twilight_exports {
             },
             Id,
         },
+        user::User,
     };
 }
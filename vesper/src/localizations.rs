@@ -1,6 +1,7 @@
 use std::collections::HashMap;
+use twilight_validate::command::{description, option_name, CommandValidationError};
 
-use crate::{prelude::Framework, command::Command, if_some};
+use crate::{prelude::{CreateCommandError, Framework}, command::Command, if_some, twilight_exports::CommandOption};
 
 pub(crate) type LocalizationsProvider<D, T, E> = fn(&Framework<D, T, E>, &Command<D, T, E>) -> HashMap<String, String>;
 
@@ -47,3 +48,38 @@ impl<D, T, E> Localizations<D, T, E> {
         self.provider = Some(provider);
     }
 }
+
+/// Validates the localized names and descriptions of the given options and all their children.
+///
+/// Localized option names must follow the same rules as the default names, but discord only
+/// validates them after the command is sent, so they are checked before registering the command.
+#[allow(clippy::result_large_err)]
+pub(crate) fn validate_options(options: &[CommandOption]) -> Result<(), CreateCommandError> {
+    for option in options {
+        validate_map(option, &option.name_localizations, |name| option_name(name))?;
+        validate_map(option, &option.description_localizations, |value| description(value))?;
+
+        if let Some(options) = &option.options {
+            validate_options(options)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn validate_map(
+    option: &CommandOption,
+    localizations: &Option<HashMap<String, String>>,
+    validator: fn(&str) -> Result<(), CommandValidationError>
+) -> Result<(), CreateCommandError> {
+    for (locale, value) in localizations.iter().flatten() {
+        validator(value).map_err(|source| CreateCommandError::Localization {
+            option: option.name.clone(),
+            locale: locale.clone(),
+            source
+        })?;
+    }
+
+    Ok(())
+}
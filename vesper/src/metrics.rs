@@ -0,0 +1,40 @@
+//! Metrics recorded for each command execution, using the [metrics](https://docs.rs/metrics)
+//! facade, so they can be exported by any of its recorders, such as Prometheus.
+//!
+//! All the metrics are labeled with the full name of the command, including its parents:
+//!
+//! - `vesper_command_invocations_total`: Counter of the commands processed by the framework.
+//! - `vesper_command_successes_total`: Counter of the commands which finished without errors.
+//! - `vesper_command_check_failures_total`: Counter of the commands which didn't execute because
+//!   a check failed.
+//! - `vesper_command_errors_total`: Counter of the commands which returned an error, either from
+//!   the command function or from a check.
+//! - `vesper_command_duration_seconds`: Histogram of the time the command functions took to
+//!   execute.
+
+use crate::command::{ExecutionResult, ExecutionState};
+use metrics::{counter, histogram};
+
+/// Records the metrics of the given command execution.
+pub(crate) fn record<T, E>(command: &str, result: &ExecutionResult<T, E>) {
+    let label = || ("command", command.to_string());
+
+    counter!("vesper_command_invocations_total", &[label()]).increment(1);
+
+    match result.state {
+        ExecutionState::CommandFinished => {
+            counter!("vesper_command_successes_total", &[label()]).increment(1);
+        },
+        ExecutionState::CommandErrored | ExecutionState::CheckErrored => {
+            counter!("vesper_command_errors_total", &[label()]).increment(1);
+        },
+        ExecutionState::CheckFailed => {
+            counter!("vesper_command_check_failures_total", &[label()]).increment(1);
+        },
+        _ => ()
+    }
+
+    if matches!(result.state, ExecutionState::CommandFinished | ExecutionState::CommandErrored) {
+        histogram!("vesper_command_duration_seconds", &[label()]).record(result.elapsed.as_secs_f64());
+    }
+}
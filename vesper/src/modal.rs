@@ -7,10 +7,14 @@ use tokio::sync::oneshot::error::RecvError;
 use twilight_model::channel::message::MessageFlags;
 use crate::context::SlashContext;
 use crate::wait::InteractionWaiter;
-use crate::twilight_exports::{Interaction, InteractionClient, InteractionResponse, InteractionResponseType, InteractionResponseData};
+use crate::twilight_exports::{
+    ActionRow, Component, Interaction, InteractionClient, InteractionResponse, InteractionResponseType,
+    InteractionResponseData, TextInput, TextInputStyle
+};
 use std::fmt::{Debug, Formatter};
 use twilight_http::response::marker::EmptyBody;
 use twilight_http::response::ResponseFuture;
+use twilight_validate::component::{text_input, ComponentValidationError, COMPONENT_COUNT, COMPONENT_CUSTOM_ID_LENGTH};
 
 
 /// Errors that can be returned when awaiting modals.
@@ -20,7 +24,149 @@ pub enum ModalError {
     /// An http error occurred.
     Http(#[from] twilight_http::Error),
     /// Something failed when using a [waiter](InteractionWaiter)
-    Waiter(#[from] RecvError)
+    Waiter(#[from] RecvError),
+    /// The modal provided to [respond_with_modal](SlashContext::respond_with_modal) is not valid.
+    Builder(#[from] ModalBuilderError),
+    /// The interaction was already acknowledged, so it can't be responded with a modal.
+    #[error("The interaction was already acknowledged, so a modal can't be sent")]
+    AlreadyAcknowledged
+}
+
+/// The maximum amount of characters the title of a modal can have.
+const MODAL_TITLE_LENGTH: usize = 45;
+
+/// Errors returned when [building](ModalBuilder::build) an invalid modal.
+#[derive(Debug, Error)]
+pub enum ModalBuilderError {
+    /// The title is empty or longer than 45 characters.
+    #[error("The modal title must have between 1 and {MODAL_TITLE_LENGTH} characters")]
+    Title,
+    /// The custom id is empty or longer than 100 characters.
+    #[error("The modal custom id must have between 1 and {COMPONENT_CUSTOM_ID_LENGTH} characters")]
+    CustomId,
+    /// The modal has no text inputs or more than 5.
+    #[error("A modal must have between 1 and {COMPONENT_COUNT} text inputs")]
+    InputCount,
+    /// A text input is not valid.
+    #[error(transparent)]
+    TextInput(#[from] ComponentValidationError)
+}
+
+/// A builder used to create modals without defining a [Modal](Modal) type, which can be sent using
+/// [respond_with_modal](SlashContext::respond_with_modal).
+///
+/// Each text input is placed in its own row, so a modal can have up to 5 inputs.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "Asks for feedback"]
+/// async fn feedback(ctx: &SlashContext<()>) -> DefaultCommandResult {
+///     let modal = ModalBuilder::new()
+///         .title("Feedback")
+///         .short_input("subject", "Subject")
+///         .paragraph_input("message", "Message");
+///
+///     let outcome = ctx.respond_with_modal(modal).await?.await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModalBuilder {
+    custom_id: String,
+    title: String,
+    inputs: Vec<TextInput>
+}
+
+impl ModalBuilder {
+    /// Creates a new, empty, modal builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the custom id of the modal, if not set, [respond_with_modal](SlashContext::respond_with_modal)
+    /// uses the interaction id.
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = custom_id.into();
+        self
+    }
+
+    /// Sets the title of the modal.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds a text input to the modal.
+    pub fn text_input(mut self, input: TextInput) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    /// Adds a required single-line text input to the modal.
+    pub fn short_input(self, custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.text_input(Self::input(custom_id.into(), label.into(), TextInputStyle::Short))
+    }
+
+    /// Adds a required multi-line text input to the modal.
+    pub fn paragraph_input(self, custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.text_input(Self::input(custom_id.into(), label.into(), TextInputStyle::Paragraph))
+    }
+
+    fn input(custom_id: String, label: String, style: TextInputStyle) -> TextInput {
+        TextInput {
+            custom_id,
+            label,
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: Some(true),
+            style,
+            value: None
+        }
+    }
+
+    /// Validates the modal, returning the response used to send it to discord.
+    pub fn build(self) -> Result<InteractionResponse, ModalBuilderError> {
+        if self.title.is_empty() || self.title.chars().count() > MODAL_TITLE_LENGTH {
+            return Err(ModalBuilderError::Title);
+        }
+
+        if self.custom_id.is_empty() || self.custom_id.chars().count() > COMPONENT_CUSTOM_ID_LENGTH {
+            return Err(ModalBuilderError::CustomId);
+        }
+
+        if self.inputs.is_empty() || self.inputs.len() > COMPONENT_COUNT {
+            return Err(ModalBuilderError::InputCount);
+        }
+
+        let mut components = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs {
+            text_input(&input)?;
+            components.push(Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(input)]
+            }));
+        }
+
+        Ok(InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(InteractionResponseData {
+                custom_id: Some(self.custom_id),
+                title: Some(self.title),
+                components: Some(components),
+                ..Default::default()
+            })
+        })
+    }
+
+    /// Returns the custom id of the modal.
+    pub(crate) fn get_custom_id(&self) -> &str {
+        &self.custom_id
+    }
 }
 
 /// The outcome of `.await`ing a [WaitModal](WaitModal).
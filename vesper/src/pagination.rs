@@ -0,0 +1,212 @@
+//! A paginator used to show multiple pages of embeds in a single response, flipping them using
+//! buttons.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use vesper::prelude::*;
+//! use vesper::pagination::Paginator;
+//! use twilight_model::channel::message::Embed;
+//!
+//! fn pages() -> Vec<Embed> {
+//!     // Create the pages here.
+//!     Vec::new()
+//! }
+//!
+//! #[command]
+//! #[description = "Shows some pages"]
+//! async fn pages_command(ctx: &SlashContext<()>) -> DefaultCommandResult {
+//!     Paginator::new(pages())
+//!         .idle_timeout(Duration::from_secs(120))
+//!         .send(ctx)
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::time::Duration;
+use futures_core::Stream;
+use twilight_model::channel::message::Embed;
+use crate::components::{ActionRowBuilder, ButtonBuilder, ComponentError};
+use crate::context::SlashContext;
+use crate::response::ResponseError;
+use crate::twilight_exports::{
+    ButtonStyle, Component, Interaction, InteractionData, InteractionResponse, InteractionResponseData,
+    InteractionResponseType
+};
+
+/// The action performed by each of the buttons of a paginator.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Previous,
+    Next,
+    Stop
+}
+
+impl Action {
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "previous" => Some(Self::Previous),
+            "next" => Some(Self::Next),
+            "stop" => Some(Self::Stop),
+            _ => None
+        }
+    }
+
+    fn id(self) -> &'static str {
+        match self {
+            Self::Previous => "previous",
+            Self::Next => "next",
+            Self::Stop => "stop"
+        }
+    }
+}
+
+/// A paginator showing a list of embeds, one at a time, with buttons to go to the previous and
+/// next pages and to stop the paginator.
+///
+/// By default, only the user who invoked the command can use the buttons, and the paginator stops
+/// after a minute without any button being used. Once stopped, the buttons are disabled.
+pub struct Paginator {
+    pages: Vec<Embed>,
+    idle_timeout: Duration,
+    only_author: bool
+}
+
+impl Paginator {
+    /// Creates a new paginator showing the given pages.
+    pub fn new(pages: Vec<Embed>) -> Self {
+        Self {
+            pages,
+            idle_timeout: Duration::from_secs(60),
+            only_author: true
+        }
+    }
+
+    /// Sets the time the paginator waits for a button to be used before stopping.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Sets whether only the user who invoked the command can use the buttons.
+    pub fn only_author(mut self, only_author: bool) -> Self {
+        self.only_author = only_author;
+        self
+    }
+
+    /// Responds to the interaction with the first page, and flips the pages as the buttons are
+    /// used, returning once the paginator is stopped or times out.
+    ///
+    /// If the interaction was already acknowledged, the original response is edited instead.
+    pub async fn send<D: Sync>(self, ctx: &SlashContext<'_, D>) -> Result<(), ResponseError> {
+        if self.pages.is_empty() {
+            return Err(ResponseError::Empty);
+        }
+
+        let prefix = format!("{}:paginator:", ctx.interaction.id);
+        let mut page = 0;
+
+        ctx.respond()
+            .embeds(vec![self.pages[page].clone()])
+            .components(self.buttons(&prefix, page, false)?)
+            .await?;
+
+        let author = ctx.interaction.author_id();
+        let only_author = self.only_author;
+        let stream_prefix = prefix.clone();
+        let mut stream = ctx.wait_component_stream(move |interaction| {
+            let Some(InteractionData::MessageComponent(data)) = &interaction.data else {
+                return false;
+            };
+
+            data.custom_id.starts_with(&stream_prefix) && (!only_author || interaction.author_id() == author)
+        });
+
+        loop {
+            let next = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx));
+            let Ok(Some(interaction)) = tokio::time::timeout(self.idle_timeout, next).await else {
+                break;
+            };
+
+            let action = custom_id(&interaction)
+                .and_then(|id| id.strip_prefix(&prefix))
+                .and_then(Action::from_id);
+
+            match action {
+                Some(Action::Previous) => page = page.saturating_sub(1),
+                Some(Action::Next) => page = (page + 1).min(self.pages.len() - 1),
+                Some(Action::Stop) => {
+                    stream.stop();
+                    return self.update(ctx, &interaction, &prefix, page, true).await;
+                },
+                None => continue
+            }
+
+            self.update(ctx, &interaction, &prefix, page, false).await?;
+        }
+
+        stream.stop();
+        ctx.update_response()
+            .components(Some(&self.buttons(&prefix, page, true)?))?
+            .await?;
+
+        Ok(())
+    }
+
+    /// Updates the message of the paginator to show the given page, responding to the given
+    /// component interaction.
+    async fn update<D>(
+        &self,
+        ctx: &SlashContext<'_, D>,
+        interaction: &Interaction,
+        prefix: &str,
+        page: usize,
+        disabled: bool
+    ) -> Result<(), ResponseError> {
+        ctx.interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::UpdateMessage,
+                    data: Some(InteractionResponseData {
+                        embeds: Some(vec![self.pages[page].clone()]),
+                        components: Some(self.buttons(prefix, page, disabled)?),
+                        ..Default::default()
+                    })
+                }
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Builds the buttons of the paginator for the given page.
+    fn buttons(&self, prefix: &str, page: usize, disabled: bool) -> Result<Vec<Component>, ComponentError> {
+        let button = |action: Action, label: &str, style: ButtonStyle, disable: bool| {
+            ButtonBuilder::new(style, format!("{}{}", prefix, action.id()))
+                .label(label)
+                .disabled(disabled || disable)
+        };
+
+        let row = ActionRowBuilder::new()
+            .button(button(Action::Previous, "Previous", ButtonStyle::Secondary, page == 0))
+            .button(button(Action::Next, "Next", ButtonStyle::Secondary, page + 1 >= self.pages.len()))
+            .button(button(Action::Stop, "Stop", ButtonStyle::Danger, false));
+
+        Ok(vec![row.build()?])
+    }
+}
+
+/// Returns the custom id of the given component interaction.
+fn custom_id(interaction: &Interaction) -> Option<&str> {
+    match &interaction.data {
+        Some(InteractionData::MessageComponent(data)) => Some(&data.custom_id),
+        _ => None
+    }
+}
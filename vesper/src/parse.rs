@@ -4,7 +4,7 @@ use std::error::Error;
 
 /// The core trait of this framework, it is used to parse all command arguments
 #[async_trait]
-pub trait Parse<T: Send + Sync>: Sized {
+pub trait Parse<T: Send + Sync>: Sized + Send {
     /// Parses the option into the argument.
     async fn parse(
         _http_client: &WrappedClient,
@@ -28,46 +28,143 @@ pub trait Parse<T: Send + Sync>: Sized {
     }
 
     fn modify_option(_option: &mut CommandOption) {}
+
+    /// Returns the maximum amount of values this argument accepts if it is variadic, by default
+    /// arguments are not variadic.
+    ///
+    /// Variadic arguments are registered as several options named `<name>_1`, `<name>_2`...
+    /// and are parsed using [parse_variadic](Self::parse_variadic).
+    fn variadic() -> Option<usize> {
+        None
+    }
+
+    /// Parses all the values provided to a variadic argument, in the order they were registered.
+    ///
+    /// This is only called if [variadic](Self::variadic) returns `Some`.
+    async fn parse_variadic(
+        _http_client: &WrappedClient,
+        _data: &T,
+        _values: Vec<&CommandOptionValue>,
+        _resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        Err(ParseError::StructureMismatch(String::from("Argument is not variadic")))
+    }
+}
+
+/// A trait used to retrieve the target of context menu commands, this is, the message or user the
+/// command was used on.
+pub trait ParseTarget: Sized {
+    /// Retrieves the target from the given command data.
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError>;
 }
 
 /// The errors which can be returned from [Parse](self::Parse) [parse](self::Parse::parse) function.
+///
+/// This type implements [Error], so the error type of the framework can be any type convertible
+/// from it, such as `anyhow::Error`, allowing command bodies to use `?` on the results of parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "Picks some numbers"]
+/// async fn pick(ctx: &mut SlashContext<()>) -> anyhow::Result<()> {
+///     let interaction = ctx.wait_component(|_| true).await?;
+///     let numbers = ctx.select_values::<i64>(&interaction).await?;
+///
+///     Ok(())
+/// }
+///
+/// fn framework(http_client: vesper::twilight_exports::Client) -> Framework<(), (), anyhow::Error> {
+///     Framework::builder(http_client, vesper::twilight_exports::Id::new(1), ())
+///         .command(pick)
+///         .build()
+/// }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ParseError {
     /// The command arguments does not match with the framework ones.
     StructureMismatch(String),
-    /// An argument failed parsing.
-    Parsing {
+    /// A required argument was not provided.
+    MissingArgument {
+        /// The name of the missing argument.
+        argument_name: String
+    },
+    /// An argument received a value of a different type than the expected one.
+    InvalidType {
+        /// The name of the argument that failed to parse.
+        argument_name: String,
+        /// The type of the argument.
+        argument_type: String,
+        /// The kind of value the argument expected.
+        expected: String
+    },
+    /// An argument received a value outside of its allowed range.
+    OutOfRange {
         /// The name of the argument that failed to parse.
         argument_name: String,
-        /// Whether if the argument is required or not-
-        required: bool,
         /// The type of the argument.
         argument_type: String,
         /// The error message as a string.
         error: String
     },
+    /// The interaction did not include the resolved data needed to parse an argument.
+    MissingResolvedData {
+        /// The name of the argument that failed to parse.
+        argument_name: String,
+        /// The type of the argument.
+        argument_type: String
+    },
+    /// A custom error, usually returned by user implementations of [Parse](self::Parse).
+    Custom(String),
     /// Other error occurred.
     Other(Box<dyn Error + Send + Sync>),
 }
 
+impl ParseError {
+    /// Sets the name of the argument that caused the error, if the error refers to an argument.
+    pub(crate) fn set_argument_name(&mut self, name: &str) {
+        match self {
+            Self::MissingArgument { argument_name }
+            | Self::InvalidType { argument_name, .. }
+            | Self::OutOfRange { argument_name, .. }
+            | Self::MissingResolvedData { argument_name, .. } => *argument_name = name.to_string(),
+            _ => ()
+        }
+    }
+}
+
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::StructureMismatch(why) => write!(f, "Structure mismatch: {}", why),
-            Self::Parsing { argument_name, required, argument_type, error } => {
-                write!(f, "Failed to parse {}({}required {}): {}", argument_name, {
-                    if !required {
-                        "not "
-                    } else {
-                        ""
-                    }
-                }, argument_type, error)
+            Self::MissingArgument { argument_name } => write!(f, "Missing argument {}", argument_name),
+            Self::InvalidType { argument_name, argument_type, expected } => {
+                write!(f, "Failed to parse {}({}): {} expected", argument_name, argument_type, expected)
+            }
+            Self::OutOfRange { argument_name, argument_type, error } => {
+                write!(f, "Argument {}({}) out of range: {}", argument_name, argument_type, error)
+            }
+            Self::MissingResolvedData { argument_name, argument_type } => {
+                write!(f, "Missing resolved data to parse {}({})", argument_name, argument_type)
             }
+            Self::Custom(why) => write!(f, "{}", why),
             Self::Other(why) => write!(f, "Other: {}", why),
         }
     }
 }
-impl Error for ParseError {}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Other(why) => Some(why.as_ref()),
+            _ => None
+        }
+    }
+}
 
 impl From<Box<dyn Error + Send + Sync>> for ParseError {
     fn from(e: Box<dyn Error + Send + Sync>) -> Self {
@@ -75,6 +172,12 @@ impl From<Box<dyn Error + Send + Sync>> for ParseError {
     }
 }
 
+impl From<String> for ParseError {
+    fn from(why: String) -> Self {
+        Self::Custom(why)
+    }
+}
+
 impl From<&'static str> for ParseError {
     fn from(why: &'static str) -> Self {
         Self::StructureMismatch(why.to_string())
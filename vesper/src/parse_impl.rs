@@ -1,3 +1,4 @@
+use std::time::Duration;
 use twilight_model::channel::Attachment;
 use twilight_model::guild::Role;
 use twilight_model::user::User;
@@ -6,15 +7,29 @@ use crate::twilight_exports::*;
 
 const NUMBER_MAX_VALUE: i64 = 9007199254740991;
 
-pub(crate) fn error(type_name: &str, required: bool, why: &str) -> ParseError {
-    ParseError::Parsing {
+pub(crate) fn invalid_type(type_name: &str, expected: &str) -> ParseError {
+    ParseError::InvalidType {
+        argument_name: String::new(),
+        argument_type: type_name.to_string(),
+        expected: expected.to_string()
+    }
+}
+
+pub(crate) fn out_of_range(type_name: &str, why: &str) -> ParseError {
+    ParseError::OutOfRange {
         argument_name: String::new(),
-        required,
         argument_type: type_name.to_string(),
         error: why.to_string()
     }
 }
 
+pub(crate) fn missing_resolved(type_name: &str) -> ParseError {
+    ParseError::MissingResolvedData {
+        argument_name: String::new(),
+        argument_type: type_name.to_string()
+    }
+}
+
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for String {
     async fn parse(
@@ -26,7 +41,7 @@ impl<T: Send + Sync> Parse<T> for String {
         if let Some(CommandOptionValue::String(s)) = value {
             return Ok(s.to_owned());
         }
-        Err(error("String", true, "String expected"))
+        Err(invalid_type("String", "String"))
     }
 
     fn kind() -> CommandOptionType {
@@ -45,7 +60,7 @@ impl<T: Send + Sync> Parse<T> for i64 {
         if let Some(CommandOptionValue::Integer(i)) = value {
             return Ok(*i);
         }
-        Err(error("i64", true, "Integer expected"))
+        Err(invalid_type("i64", "Integer"))
     }
 
     fn kind() -> CommandOptionType {
@@ -63,11 +78,11 @@ impl<T: Send + Sync> Parse<T> for u64 {
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Integer(i)) = value {
             if *i < 0 {
-                return Err(error("u64", true, "Input out of range"))
+                return Err(out_of_range("u64", "Input out of range"))
             }
             return Ok(*i as u64);
         }
-        Err(error("Integer", true, "Integer expected"))
+        Err(invalid_type("Integer", "Integer"))
     }
 
     fn kind() -> CommandOptionType {
@@ -91,7 +106,7 @@ impl<T: Send + Sync> Parse<T> for f64 {
         if let Some(CommandOptionValue::Number(i)) = value {
             return Ok(*i);
         }
-        Err(error("f64", true, "Number expected"))
+        Err(invalid_type("f64", "Number"))
     }
 
     fn kind() -> CommandOptionType {
@@ -115,11 +130,11 @@ impl<T: Send + Sync> Parse<T> for f32 {
     ) -> Result<Self, ParseError> {
         if let Some(CommandOptionValue::Number(i)) = value {
             if *i > f32::MAX as f64 || *i < f32::MIN as f64 {
-                return Err(error("f32", true, "Input out of range"))
+                return Err(out_of_range("f32", "Input out of range"))
             }
             return Ok(*i as f32);
         }
-        Err(error("f32", true, "Number expected"))
+        Err(invalid_type("f32", "Number"))
     }
 
     fn kind() -> CommandOptionType {
@@ -133,6 +148,21 @@ impl<T: Send + Sync> Parse<T> for f32 {
     }
 }
 
+/// Parses a boolean written as text, case-insensitively.
+fn parse_bool(input: &str) -> Option<bool> {
+    const TRUE: [&str; 3] = ["yes", "true", "on"];
+    const FALSE: [&str; 3] = ["no", "false", "off"];
+
+    let input = input.trim();
+    if TRUE.iter().any(|value| value.eq_ignore_ascii_case(input)) {
+        Some(true)
+    } else if FALSE.iter().any(|value| value.eq_ignore_ascii_case(input)) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for bool {
     async fn parse(
@@ -141,10 +171,13 @@ impl<T: Send + Sync> Parse<T> for bool {
         value: Option<&CommandOptionValue>,
         _: Option<&mut CommandInteractionDataResolved>
     ) -> Result<Self, ParseError> {
-        if let Some(CommandOptionValue::Boolean(i)) = value {
-            return Ok(*i);
+        match value {
+            Some(CommandOptionValue::Boolean(i)) => Ok(*i),
+            // Booleans can also be received through string options, like "yes" or "off".
+            Some(CommandOptionValue::String(s)) => parse_bool(s)
+                .ok_or_else(|| invalid_type("Boolean", "yes/no, true/false or on/off")),
+            _ => Err(invalid_type("Boolean", "Boolean"))
         }
-        Err(error("Boolean", true, "Boolean expected"))
     }
 
     fn kind() -> CommandOptionType {
@@ -152,6 +185,140 @@ impl<T: Send + Sync> Parse<T> for bool {
     }
 }
 
+/// A description of the durations accepted by [parse_duration].
+const DURATION_FORMAT: &str = "A duration like `10m`, `2h30m` or `1d 12h`";
+
+/// Parses a human readable duration such as `10m`, `2h30m` or `1d 12h`, returning an invalid type
+/// error if the input is malformed and an out of range error if the duration is too large.
+fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    let mut rest = input.trim_start();
+
+    if rest.is_empty() {
+        return Err(invalid_type("Duration", DURATION_FORMAT));
+    }
+
+    let mut seconds = 0u64;
+
+    while !rest.is_empty() {
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let unit_end = rest[digits_end..]
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace())
+            .map(|idx| idx + digits_end)
+            .unwrap_or(rest.len());
+        let component = &rest[..unit_end];
+
+        let multiplier = match &rest[digits_end..unit_end] {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 60 * 60 * 24,
+            "w" => 60 * 60 * 24 * 7,
+            _ => return Err(invalid_type("Duration", DURATION_FORMAT))
+        };
+
+        if digits_end == 0 {
+            return Err(invalid_type("Duration", DURATION_FORMAT));
+        }
+
+        // The component only contains digits, so it can only fail because it is too large.
+        seconds = rest[..digits_end].parse::<u64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(multiplier))
+            .and_then(|amount| seconds.checked_add(amount))
+            .ok_or_else(|| out_of_range("Duration", &format!("Duration component `{}` is too large", component)))?;
+
+        rest = rest[unit_end..].trim_start();
+    }
+
+    Ok(Duration::from_secs(seconds))
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Duration {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let input = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("Duration", "String"))?;
+
+        parse_duration(&input)
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// The formats accepted for dates without an offset, which are assumed to be in UTC.
+#[cfg(feature = "time")]
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "[year]-[month]-[day]T[hour]:[minute]:[second]",
+    "[year]-[month]-[day] [hour]:[minute]:[second]",
+    "[year]-[month]-[day]T[hour]:[minute]",
+    "[year]-[month]-[day] [hour]:[minute]",
+];
+
+/// Parses a date and time, accepting RFC 3339 dates such as `2024-01-31T18:30:00Z`, dates
+/// without offset such as `2024-01-31 18:30` or `2024-01-31`, unix timestamps and discord
+/// timestamps such as `<t:1706725800:R>`.
+#[cfg(feature = "time")]
+fn parse_date_time(input: &str) -> Result<::time::OffsetDateTime, ParseError> {
+    use ::time::{format_description, format_description::well_known::Rfc3339, Date, OffsetDateTime, PrimitiveDateTime};
+
+    let input = input.trim();
+    let timestamp = input.strip_prefix("<t:")
+        .and_then(|rest| rest.strip_suffix('>'))
+        .map(|rest| rest.split(':').next().unwrap_or_default())
+        .unwrap_or(input);
+
+    if let Ok(timestamp) = timestamp.parse::<i64>() {
+        return OffsetDateTime::from_unix_timestamp(timestamp)
+            .map_err(|_| out_of_range("OffsetDateTime", &format!("Timestamp {} out of range", timestamp)));
+    }
+
+    if let Ok(date_time) = OffsetDateTime::parse(input, &Rfc3339) {
+        return Ok(date_time);
+    }
+
+    for format in DATE_TIME_FORMATS {
+        let format = format_description::parse_borrowed::<2>(format).expect("Date time formats are valid");
+        if let Ok(date_time) = PrimitiveDateTime::parse(input, &format) {
+            return Ok(date_time.assume_utc());
+        }
+    }
+
+    let format = format_description::parse_borrowed::<2>("[year]-[month]-[day]").expect("Date format is valid");
+    Date::parse(input, &format)
+        .map(|date| date.midnight().assume_utc())
+        .map_err(|_| invalid_type(
+            "OffsetDateTime",
+            "A date like `2024-01-31T18:30:00Z`, `2024-01-31 18:30` or `2024-01-31`"
+        ))
+}
+
+#[cfg(feature = "time")]
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for ::time::OffsetDateTime {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let input = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("OffsetDateTime", "String"))?;
+
+        parse_date_time(&input)
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for Id<AttachmentMarker> {
     async fn parse(
@@ -164,7 +331,7 @@ impl<T: Send + Sync> Parse<T> for Id<AttachmentMarker> {
             return Ok(*attachment);
         }
 
-        Err(error("Attachment id", true, "Attachment expected"))
+        Err(invalid_type("Attachment id", "Attachment"))
     }
 
     fn kind() -> CommandOptionType {
@@ -182,9 +349,8 @@ impl<T: Send + Sync> Parse<T> for Attachment {
     ) -> Result<Self, ParseError> {
         let id = <Id<AttachmentMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
 
-        resolved.map(|item| item.attachments.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("Attachment", true, "Attachment expected"))
+        resolved.and_then(|item| item.attachments.remove(&id))
+            .ok_or_else(|| missing_resolved("Attachment"))
     }
 
     fn kind() -> CommandOptionType {
@@ -204,7 +370,7 @@ impl<T: Send + Sync> Parse<T> for Id<ChannelMarker> {
             return Ok(*channel);
         }
 
-        Err(error("Channel id", true, "Channel expected"))
+        Err(invalid_type("Channel id", "Channel"))
     }
 
     fn kind() -> CommandOptionType {
@@ -224,7 +390,7 @@ impl<T: Send + Sync> Parse<T> for Id<UserMarker> {
             return Ok(*user);
         }
 
-        Err(error("User id", true, "User expected"))
+        Err(invalid_type("User id", "User"))
     }
 
     fn kind() -> CommandOptionType {
@@ -232,6 +398,24 @@ impl<T: Send + Sync> Parse<T> for Id<UserMarker> {
     }
 }
 
+/// Fetches the user with the given id, used when discord doesn't include it in the resolved data
+/// of the interaction.
+#[cfg(feature = "fetch-missing")]
+async fn missing_user(http_client: &WrappedClient, id: Id<UserMarker>) -> Result<User, ParseError> {
+    http_client.inner()
+        .user(id)
+        .await
+        .map_err(|why| ParseError::Other(Box::new(why)))?
+        .model()
+        .await
+        .map_err(|why| ParseError::Other(Box::new(why)))
+}
+
+#[cfg(not(feature = "fetch-missing"))]
+async fn missing_user(_: &WrappedClient, _: Id<UserMarker>) -> Result<User, ParseError> {
+    Err(missing_resolved("User"))
+}
+
 #[async_trait]
 impl<T: Send + Sync> Parse<T> for User {
     async fn parse(
@@ -242,9 +426,10 @@ impl<T: Send + Sync> Parse<T> for User {
     ) -> Result<Self, ParseError> {
         let id = <Id<UserMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
 
-        resolved.map(|items| items.users.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("User", true, "User expected"))
+        match resolved.and_then(|items| items.users.remove(&id)) {
+            Some(user) => Ok(user),
+            None => missing_user(http_client, id).await
+        }
     }
 
     fn kind() -> CommandOptionType {
@@ -264,7 +449,7 @@ impl<T: Send + Sync> Parse<T> for Id<RoleMarker> {
             return Ok(*role);
         }
 
-        Err(error("Role id", true, "Role expected"))
+        Err(invalid_type("Role id", "Role"))
     }
 
     fn kind() -> CommandOptionType {
@@ -282,9 +467,9 @@ impl<T: Send + Sync> Parse<T> for Role {
     ) -> Result<Self, ParseError> {
         let id = <Id<RoleMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
 
-        resolved.map(|items| items.roles.remove(&id))
-            .flatten()
-            .ok_or_else(|| error("Role", true, "Role expected"))
+        // Roles can't be fetched without knowing the guild they belong to.
+        resolved.and_then(|items| items.roles.remove(&id))
+            .ok_or_else(|| missing_resolved("Role"))
     }
 
     fn kind() -> CommandOptionType {
@@ -304,7 +489,7 @@ impl<T: Send + Sync> Parse<T> for Id<GenericMarker> {
             return Ok(*id);
         }
 
-        Err(error("Id", true, "Mentionable expected"))
+        Err(invalid_type("Id", "Mentionable"))
     }
 
     fn kind() -> CommandOptionType {
@@ -322,12 +507,8 @@ impl<T: Parse<E>, E: Send + Sync> Parse<E> for Option<T> {
     ) -> Result<Self, ParseError> {
         match T::parse(http_client, data, value, resolved).await {
             Ok(parsed) => Ok(Some(parsed)),
-            Err(mut why) => {
+            Err(why) => {
                 if value.is_some() {
-                    if let ParseError::Parsing {required, ..} = &mut why {
-                        *required = false;
-                    }
-
                     Err(why)
                 } else {
                     Ok(None)
@@ -351,13 +532,30 @@ impl<T: Parse<E>, E: Send + Sync> Parse<E> for Option<T> {
     fn modify_option(option: &mut CommandOption) {
         T::modify_option(option)
     }
+
+    fn variadic() -> Option<usize> {
+        T::variadic()
+    }
+
+    async fn parse_variadic(
+        http_client: &WrappedClient,
+        data: &E,
+        values: Vec<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        if values.is_empty() {
+            return Ok(None);
+        }
+
+        T::parse_variadic(http_client, data, values, resolved).await.map(Some)
+    }
 }
 
 #[async_trait]
 impl<T, E, C> Parse<C> for Result<T, E>
 where
     T: Parse<C>,
-    E: From<ParseError>,
+    E: From<ParseError> + Send,
     C: Send + Sync,
 {
     async fn parse(
@@ -385,6 +583,19 @@ where
     fn modify_option(option: &mut CommandOption) {
         T::modify_option(option)
     }
+
+    fn variadic() -> Option<usize> {
+        T::variadic()
+    }
+
+    async fn parse_variadic(
+        http_client: &WrappedClient,
+        data: &C,
+        values: Vec<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        Ok(T::parse_variadic(http_client, data, values, resolved).await.map_err(From::from))
+    }
 }
 
 macro_rules! impl_derived_parse {
@@ -401,9 +612,8 @@ macro_rules! impl_derived_parse {
                     let p = <$prim>::parse(http_client, data, value, resolved).await?;
 
                     if p > <$derived>::MAX as $prim {
-                        Err(error(
+                        Err(out_of_range(
                             stringify!($derived),
-                            true,
                             concat!(
                                 "Failed to parse to ",
                                 stringify!($derived),
@@ -414,9 +624,8 @@ macro_rules! impl_derived_parse {
                             )
                         ))
                     } else if p < <$derived>::MIN as $prim {
-                        Err(error(
+                        Err(out_of_range(
                             stringify!($derived),
-                            true,
                             concat!(
                                 "Failed to parse to ",
                                 stringify!($derived),
@@ -456,3 +665,108 @@ impl_derived_parse! {
     [i8, i16, i32, isize] from i64,
     [u8, u16, u32, usize] from u64,
 }
+
+macro_rules! impl_non_zero_parse {
+    ($($non_zero:ident from $prim:ty),* $(,)?) => {
+        $(
+            #[async_trait]
+            impl<T: Send + Sync> Parse<T> for std::num::$non_zero {
+                async fn parse(
+                    http_client: &WrappedClient,
+                    data: &T,
+                    value: Option<&CommandOptionValue>,
+                    resolved: Option<&mut CommandInteractionDataResolved>
+                ) -> Result<Self, ParseError> {
+                    let p = <$prim>::parse(http_client, data, value, resolved).await?;
+
+                    Self::new(p).ok_or_else(|| out_of_range(
+                        stringify!($non_zero),
+                        concat!("Failed to parse to ", stringify!($non_zero), ": the value is zero")
+                    ))
+                }
+
+                fn kind() -> CommandOptionType {
+                    <$prim as Parse<T>>::kind()
+                }
+
+                fn modify_option(option: &mut CommandOption) {
+                    use twilight_model::application::command::CommandOptionValue;
+                    <$prim as Parse<T>>::modify_option(option);
+
+                    // Unsigned values can only be zero at their minimum, so zero can be excluded
+                    // by discord itself.
+                    if let Some(CommandOptionValue::Integer(0)) = option.min_value {
+                        option.min_value = Some(CommandOptionValue::Integer(1));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_parse! {
+    NonZeroI8 from i8,
+    NonZeroI16 from i16,
+    NonZeroI32 from i32,
+    NonZeroI64 from i64,
+    NonZeroIsize from isize,
+    NonZeroU8 from u8,
+    NonZeroU16 from u16,
+    NonZeroU32 from u32,
+    NonZeroU64 from u64,
+    NonZeroUsize from usize,
+}
+
+fn target_id(data: &CommandData) -> Result<Id<GenericMarker>, ParseError> {
+    data.target_id
+        .ok_or_else(|| ParseError::StructureMismatch(String::from("Command has no target")))
+}
+
+impl ParseTarget for Id<MessageMarker> {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        target_id(data).map(Id::cast)
+    }
+}
+
+impl ParseTarget for Message {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        let id = target_id(data)?.cast();
+        data.resolved.as_mut()
+            .and_then(|resolved| resolved.messages.remove(&id))
+            .ok_or_else(|| missing_resolved("Message"))
+    }
+}
+
+impl ParseTarget for Id<UserMarker> {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        target_id(data).map(Id::cast)
+    }
+}
+
+impl ParseTarget for User {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        let id = target_id(data)?.cast();
+        data.resolved.as_mut()
+            .and_then(|resolved| resolved.users.remove(&id))
+            .ok_or_else(|| missing_resolved("User"))
+    }
+}
+
+impl ParseTarget for InteractionMember {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        let id = target_id(data)?.cast();
+        data.resolved.as_mut()
+            .and_then(|resolved| resolved.members.remove(&id))
+            .ok_or_else(|| missing_resolved("InteractionMember"))
+    }
+}
+
+impl<T: ParseTarget> ParseTarget for Option<T> {
+    fn parse_target(data: &mut CommandData) -> Result<Self, ParseError> {
+        match T::parse_target(data) {
+            Ok(target) => Ok(Some(target)),
+            Err(ParseError::MissingResolvedData { .. }) => Ok(None),
+            Err(why) => Err(why)
+        }
+    }
+}
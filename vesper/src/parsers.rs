@@ -3,11 +3,13 @@ use async_trait::async_trait;
 use twilight_model::application::command::{CommandOption, CommandOptionType};
 use twilight_model::application::interaction::application_command::{CommandInteractionDataResolved, CommandOptionValue, InteractionChannel};
 use twilight_model::channel::ChannelType;
+use twilight_model::guild::Role;
 use twilight_model::id::Id;
-use twilight_model::id::marker::ChannelMarker;
+use twilight_model::id::marker::{ChannelMarker, GenericMarker, GuildMarker, MessageMarker};
+use twilight_model::user::User;
 use crate::builder::WrappedClient;
 use crate::parse::{Parse, ParseError};
-use crate::parse_impl::error;
+use crate::parse_impl::{invalid_type, missing_resolved};
 
 macro_rules! newtype_struct {
     ($($(#[$meta:meta])* $v: vis struct $name: ident($inner: ty)),* $(,)?) => {
@@ -65,12 +67,12 @@ macro_rules! parse_id {
 }
 
 macro_rules! parse_derived_channel {
-    ($($name_t: ty, $id: ty, $name: literal),* $(,)?) => {
+    ($($name_t: ty, $id: ty, $name: literal, [$($allowed: expr),* $(,)?]),* $(,)?) => {
         $(
-            parse_derived_channel!(@inner $name_t, $id, $name);
+            parse_derived_channel!(@inner $name_t, $id, $name, [$($allowed),*]);
         )*
     };
-    (@inner $name_t: ty, $id: ty, $name: literal) => {
+    (@inner $name_t: ty, $id: ty, $name: literal, [$($allowed: expr),* $(,)?]) => {
         #[async_trait]
         impl<T: Send + Sync> Parse<T> for $name_t {
             async fn parse(
@@ -83,7 +85,8 @@ macro_rules! parse_derived_channel {
 
                 resolved.map(|items| items.channels.remove(&*id))
                     .flatten()
-                    .ok_or_else(|| error($name, true, concat!($name, " expected")))
+                    .ok_or_else(|| missing_resolved($name))
+                    .and_then(|channel| check_channel_kind($name, channel, &[$($allowed),*]))
                     .map(Self)
             }
 
@@ -98,6 +101,20 @@ macro_rules! parse_derived_channel {
     };
 }
 
+/// Returns the given channel if it is one of the allowed kinds.
+fn check_channel_kind(
+    type_name: &str,
+    channel: InteractionChannel,
+    allowed: &[ChannelType]
+) -> Result<InteractionChannel, ParseError> {
+    if allowed.contains(&channel.kind) {
+        Ok(channel)
+    } else {
+        let allowed = allowed.iter().map(|kind| kind.name()).collect::<Vec<_>>().join(", ");
+        Err(invalid_type(type_name, &format!("A channel of type {}", allowed)))
+    }
+}
+
 newtype_struct! {
     /// An object that parses into a discord only **text** channel.
     pub struct TextChannel(InteractionChannel),
@@ -118,7 +135,12 @@ newtype_struct! {
     /// An object that parses into a discord **either public, private or announcement** thread.
     pub struct Thread(InteractionChannel),
     /// An object that parses into a discord **either public, private or announcement** thread id.
-    pub struct ThreadId(Id<ChannelMarker>)
+    pub struct ThreadId(Id<ChannelMarker>),
+    /// An object that parses into an **http or https** url.
+    pub struct Url(String),
+    /// An object that parses a hex color code, like `#FF8800`, `FF8800` or `0xFF8800`, into its
+    /// `u32` value.
+    pub struct Color(u32)
 }
 
 parse_id! {
@@ -130,9 +152,304 @@ parse_id! {
 }
 
 parse_derived_channel! {
-    TextChannel, TextChannelId, "Text Channel",
-    VoiceChannel, VoiceChannelId, "Voice Channel",
-    PublicThread, PublicThreadId, "Public Thread",
-    PrivateThread, PrivateThreadId, "Private Thread",
-    Thread, ThreadId, "Thread"
+    TextChannel, TextChannelId, "Text Channel", [ChannelType::GuildText],
+    VoiceChannel, VoiceChannelId, "Voice Channel", [ChannelType::GuildVoice],
+    PublicThread, PublicThreadId, "Public Thread", [ChannelType::PublicThread],
+    PrivateThread, PrivateThreadId, "Private Thread", [ChannelType::PrivateThread],
+    Thread, ThreadId, "Thread", [ChannelType::PublicThread, ChannelType::PrivateThread]
+}
+
+/// An object that parses into a discord channel of the given [type](ChannelType), specified by
+/// its numeric value, like `TypedChannel<15>` for forum channels.
+///
+/// Only channels of the given type are shown to the user, and channels of any other type are
+/// rejected when parsing the argument.
+#[derive(Clone, Debug)]
+pub struct TypedChannel<const KIND: u8>(pub InteractionChannel);
+
+impl<const KIND: u8> Deref for TypedChannel<KIND> {
+    type Target = InteractionChannel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const KIND: u8> DerefMut for TypedChannel<KIND> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, const KIND: u8> Parse<T> for TypedChannel<KIND> {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let id = <Id<ChannelMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
+
+        resolved.and_then(|items| items.channels.remove(&id))
+            .ok_or_else(|| missing_resolved("TypedChannel"))
+            .and_then(|channel| check_channel_kind("TypedChannel", channel, &[ChannelType::from(KIND)]))
+            .map(Self)
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::Channel
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        option.channel_types = Some(vec![ChannelType::from(KIND)]);
+    }
+}
+
+/// An object that parses into either a discord **user** or a discord **role**, using discord's
+/// `Mentionable` option type.
+#[derive(Clone, Debug)]
+pub enum Mentionable {
+    /// The user provided a user.
+    User(User),
+    /// The user provided a role.
+    Role(Role)
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Mentionable {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let id = <Id<GenericMarker> as Parse<T>>::parse(http_client, data, value, None).await?;
+        let resolved = resolved.ok_or_else(|| missing_resolved("Mentionable"))?;
+
+        if let Some(user) = resolved.users.remove(&id.cast()) {
+            Ok(Self::User(user))
+        } else if let Some(role) = resolved.roles.remove(&id.cast()) {
+            Ok(Self::Role(role))
+        } else {
+            Err(missing_resolved("Mentionable"))
+        }
+    }
+
+    fn kind() -> CommandOptionType {
+        <Id<GenericMarker> as Parse<T>>::kind()
+    }
+}
+
+/// Returns whether the given url is a valid http or https url.
+fn is_valid_url(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return false;
+    }
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty() && !url.contains(char::is_whitespace)
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Url {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let url = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("Url", "String"))?;
+
+        if !is_valid_url(url.trim()) {
+            return Err(invalid_type("Url", "An http or https url"));
+        }
+
+        Ok(Self(url.trim().to_string()))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// Parses the given hex color code, returning `None` if it isn't a valid color.
+fn parse_color(input: &str) -> Option<u32> {
+    let hex = input.strip_prefix('#')
+        .or_else(|| input.strip_prefix("0x"))
+        .or_else(|| input.strip_prefix("0X"))
+        .unwrap_or(input);
+
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(u32::from_str_radix(hex, 16).expect("Color is valid hex"))
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for Color {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let color = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("Color", "String"))?;
+
+        parse_color(color.trim())
+            .map(Self)
+            .ok_or_else(|| invalid_type("Color", "6 hex digits like `#FF8800`"))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// An object that parses a reference to a discord message, either a raw message id or a message
+/// link, like `https://discord.com/channels/{guild}/{channel}/{message}`.
+///
+/// Links to messages sent in direct messages use `@me` instead of the guild id. The referenced
+/// message can be retrieved using [fetch_message](crate::context::SlashContext::fetch_message).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageLink {
+    /// The guild the message was sent in, `None` if the message was sent in direct messages or
+    /// only its id was provided.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// The channel the message was sent in, `None` if only the id of the message was provided.
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// The id of the message.
+    pub message_id: Id<MessageMarker>
+}
+
+/// Parses a non zero snowflake.
+fn parse_snowflake<M>(input: &str) -> Option<Id<M>> {
+    input.parse::<u64>().ok().and_then(Id::new_checked)
+}
+
+/// Parses the given message id or link, returning `None` if it isn't valid.
+fn parse_message_link(input: &str) -> Option<MessageLink> {
+    if let Some(message_id) = parse_snowflake(input) {
+        return Some(MessageLink {
+            guild_id: None,
+            channel_id: None,
+            message_id
+        });
+    }
+
+    let rest = input.strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))?;
+    let (host, path) = rest.split_once('/')?;
+
+    let host = host.strip_prefix("ptb.")
+        .or_else(|| host.strip_prefix("canary."))
+        .unwrap_or(host);
+    if host != "discord.com" && host != "discordapp.com" {
+        return None;
+    }
+
+    let mut segments = path.trim_end_matches('/').split('/');
+    let (Some("channels"), Some(guild), Some(channel), Some(message), None) = (
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next()
+    ) else {
+        return None;
+    };
+
+    let guild_id = match guild {
+        "@me" => None,
+        guild => Some(parse_snowflake(guild)?)
+    };
+
+    Some(MessageLink {
+        guild_id,
+        channel_id: Some(parse_snowflake(channel)?),
+        message_id: parse_snowflake(message)?
+    })
+}
+
+#[async_trait]
+impl<T: Send + Sync> Parse<T> for MessageLink {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let link = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("MessageLink", "String"))?;
+
+        parse_message_link(link.trim())
+            .ok_or_else(|| invalid_type("MessageLink", "A message id or link"))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+}
+
+/// An object that parses a raw snowflake, provided as a string, into an id of the given marker,
+/// like `Snowflake<GuildMarker>`.
+///
+/// This allows taking ids of entities discord can't resolve as mentions, like users, roles or
+/// channels of other guilds. Only the id is validated, so the entity it refers to may not exist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Snowflake<M>(pub Id<M>);
+
+impl<M> Snowflake<M> {
+    /// Returns the parsed id.
+    pub fn into_inner(self) -> Id<M> {
+        self.0
+    }
+}
+
+impl<M> Deref for Snowflake<M> {
+    type Target = Id<M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<M> From<Snowflake<M>> for Id<M> {
+    fn from(snowflake: Snowflake<M>) -> Self {
+        snowflake.0
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, M: Send + Sync> Parse<T> for Snowflake<M> {
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let id = <String as Parse<T>>::parse(http_client, data, value, resolved).await
+            .map_err(|_| invalid_type("Snowflake", "String"))?;
+
+        parse_snowflake(id.trim())
+            .map(Self)
+            .ok_or_else(|| invalid_type("Snowflake", "A discord id"))
+    }
+
+    fn kind() -> CommandOptionType {
+        CommandOptionType::String
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        // The largest snowflake, u64::MAX, has 20 digits.
+        option.min_length = Some(1);
+        option.max_length = Some(20);
+    }
 }
@@ -1,7 +1,7 @@
 use std::any::type_name;
 use crate::prelude::*;
 use crate::twilight_exports::*;
-use crate::parse_impl::error;
+use crate::parse_impl::out_of_range;
 use std::ops::{Deref, DerefMut};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
@@ -34,6 +34,10 @@ use sealed::Number;
 /// A range-like type used to constraint the input provided by the user. This is equivalent to
 /// using a [RangeInclusive], but implements the [parse] trait.
 ///
+/// The bounds are both registered in discord, so the client enforces them, and checked when
+/// parsing, since values outside of the range can still be sent by older clients or directly
+/// using the API.
+///
 /// [RangeInclusive]: std::ops::RangeInclusive
 /// [parse]: Parse
 #[derive(Copy, Clone)]
@@ -69,10 +73,9 @@ impl<T, E, const START: i64, const END: i64> Parse<T> for Range<E, START, END>
         let v = value.as_i64();
 
         if v < START || v > END {
-            return Err(error(
+            return Err(out_of_range(
                 &format!("Range<{}, {}, {}>", type_name::<E>(), START, END),
-                true,
-                "Input out of range"
+                &format!("Input {} out of range, expected a value between {} and {}", v, START, END)
             ));
         }
 
@@ -0,0 +1,260 @@
+use std::future::IntoFuture;
+use thiserror::Error;
+use twilight_model::channel::message::{AllowedMentions, Embed, MessageFlags};
+use twilight_model::http::attachment::Attachment;
+use twilight_validate::component::COMPONENT_COUNT;
+use twilight_validate::message::MessageValidationError;
+use crate::components::{build_rows, ActionRowBuilder, ComponentError};
+use crate::context::SlashContext;
+use crate::embeds::{validate_embeds, EmbedBuilder, EmbedError};
+use crate::twilight_exports::{Component, InteractionResponse, InteractionResponseData, InteractionResponseType};
+use crate::BoxFuture;
+
+/// The maximum amount of attachments a message can have.
+pub const ATTACHMENT_COUNT: usize = 10;
+/// The maximum combined size, in bytes, of the attachments of a message.
+pub const ATTACHMENT_SIZE: usize = 25 * 1024 * 1024;
+
+/// Errors returned when the attachments of a message exceed discord limits.
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    /// The message has more than [ATTACHMENT_COUNT] attachments.
+    #[error("A message can have at most {} attachments, but {count} were provided", ATTACHMENT_COUNT)]
+    TooMany {
+        /// The amount of attachments provided.
+        count: usize
+    },
+    /// The attachments of the message are bigger than [ATTACHMENT_SIZE] combined.
+    #[error("The attachments of a message can be at most {} bytes combined, but they are {size} bytes", ATTACHMENT_SIZE)]
+    TooLarge {
+        /// The combined size of the attachments, in bytes.
+        size: usize
+    }
+}
+
+/// Errors that can be returned when sending a response using a [response builder](ResponseBuilder).
+#[derive(Debug, Error)]
+pub enum ResponseError {
+    /// The response had no content, embeds, components nor attachments.
+    #[error("The response must have at least content, embeds, components or attachments")]
+    Empty,
+    /// The attachments of the response exceed discord limits.
+    #[error(transparent)]
+    Attachment(#[from] AttachmentError),
+    /// An http error occurred.
+    #[error(transparent)]
+    Http(#[from] twilight_http::Error),
+    /// The response did not pass discord message validation.
+    #[error(transparent)]
+    Validation(#[from] MessageValidationError),
+    /// The components added using [action_row](ResponseBuilder::action_row) are not valid.
+    #[error(transparent)]
+    Component(#[from] ComponentError),
+    /// The embeds of the response exceed discord limits.
+    #[error(transparent)]
+    Embed(#[from] EmbedError)
+}
+
+/// A builder used to respond to an interaction. This can be obtained by using
+/// [SlashContext::respond](SlashContext::respond).
+///
+/// To send the response, `.await` the builder.
+///
+/// If the interaction was already acknowledged, for example by deferring it, the original response
+/// will be edited instead of creating a new one.
+#[must_use = "Responses are not sent unless the builder is awaited"]
+pub struct ResponseBuilder<'ctx, D> {
+    context: &'ctx SlashContext<'ctx, D>,
+    data: InteractionResponseData,
+    rows: Vec<ActionRowBuilder>,
+    embeds: Vec<EmbedBuilder>
+}
+
+impl<'ctx, D> ResponseBuilder<'ctx, D> {
+    pub(crate) fn new(context: &'ctx SlashContext<'ctx, D>) -> Self {
+        Self {
+            context,
+            data: InteractionResponseData {
+                flags: context.is_ephemeral().then_some(MessageFlags::EPHEMERAL),
+                ..Default::default()
+            },
+            rows: Vec::new(),
+            embeds: Vec::new()
+        }
+    }
+
+    /// Sets the content of the response.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.data.content = Some(content.into());
+        self
+    }
+
+    /// Sets the embeds of the response.
+    ///
+    /// The amount and length of the embeds are validated when the response is sent.
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.data.embeds = Some(embeds);
+        self
+    }
+
+    /// Adds an embed to the response, the embed is validated when the response is sent.
+    pub fn embed(mut self, embed: EmbedBuilder) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Sets the components of the response.
+    pub fn components(mut self, components: Vec<Component>) -> Self {
+        self.data.components = Some(components);
+        self
+    }
+
+    /// Sets the files attached to the response, replacing the ones added before.
+    ///
+    /// The amount and size of the attachments are validated when the response is sent.
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.data.attachments = Some(attachments);
+        self
+    }
+
+    /// Attaches a file with the given name and content to the response.
+    pub fn add_attachment(mut self, filename: impl Into<String>, file: Vec<u8>) -> Self {
+        let attachments = self.data.attachments.get_or_insert_with(Vec::new);
+        // Attachment ids only have to be unique within the message.
+        let id = attachments.iter().map(|attachment| attachment.id + 1).max().unwrap_or(0);
+
+        attachments.push(Attachment::from_bytes(filename.into(), file, id));
+        self
+    }
+
+    /// Adds an action row to the components of the response, the row is validated when the
+    /// response is sent.
+    pub fn action_row(mut self, row: ActionRowBuilder) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Marks the response as ephemeral, so it will only be visible to the user who invoked the
+    /// command.
+    ///
+    /// This has no effect if the interaction was already acknowledged.
+    pub fn ephemeral(self) -> Self {
+        self.set_ephemeral(true)
+    }
+
+    /// Sets whether the response is ephemeral, overriding the default set by
+    /// [Command::ephemeral](crate::command::Command::ephemeral).
+    ///
+    /// This has no effect if the interaction was already acknowledged.
+    pub fn set_ephemeral(mut self, ephemeral: bool) -> Self {
+        let mut flags = self.data.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(MessageFlags::EPHEMERAL, ephemeral);
+        self.data.flags = Some(flags).filter(|flags| !flags.is_empty());
+        self
+    }
+
+    /// Sets whether the response should be sent as a text to speech message.
+    ///
+    /// This has no effect if the interaction was already acknowledged.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.data.tts = Some(tts);
+        self
+    }
+
+    /// Sets the mentions allowed in the response.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.data.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    async fn send(self) -> Result<(), ResponseError> {
+        let Self { context, mut data, rows, embeds } = self;
+
+        if !embeds.is_empty() {
+            let built = embeds.into_iter()
+                .map(EmbedBuilder::build)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            data.embeds.get_or_insert_with(Vec::new).extend(built);
+        }
+
+        if let Some(embeds) = &data.embeds {
+            validate_embeds(embeds)?;
+        }
+
+        if !rows.is_empty() {
+            let components = data.components.get_or_insert_with(Vec::new);
+            components.extend(build_rows(rows)?);
+
+            if components.len() > COMPONENT_COUNT {
+                return Err(ComponentError::TooManyRows.into());
+            }
+        }
+
+        if let Some(attachments) = &data.attachments {
+            validate_attachments(attachments)?;
+        }
+
+        if data.content.is_none() && data.embeds.is_none() && data.components.is_none() && data.attachments.is_none() {
+            return Err(ResponseError::Empty);
+        }
+
+        if context.set_acknowledged() {
+            context.update_response()
+                .content(data.content.as_deref())?
+                .embeds(data.embeds.as_deref())?
+                .components(data.components.as_deref())?
+                .attachments(data.attachments.as_deref().unwrap_or_default())?
+                .allowed_mentions(data.allowed_mentions.as_ref())
+                .await?;
+
+            return Ok(());
+        }
+
+        let response = context.interaction_client
+            .create_response(
+                context.interaction.id,
+                &context.interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::ChannelMessageWithSource,
+                    data: Some(data)
+                }
+            )
+            .await;
+
+        if let Err(why) = response {
+            // The interaction wasn't acknowledged, so allow trying again.
+            context.unset_acknowledged();
+            return Err(why.into());
+        }
+
+        context.set_responded();
+        Ok(())
+    }
+}
+
+/// Validates the amount and combined size of the given attachments against discord limits.
+///
+/// Responses sent using a [response builder](ResponseBuilder) are validated automatically, this
+/// can be used to validate the attachments of [followups](SlashContext::create_followup).
+pub fn validate_attachments(attachments: &[Attachment]) -> Result<(), AttachmentError> {
+    if attachments.len() > ATTACHMENT_COUNT {
+        return Err(AttachmentError::TooMany { count: attachments.len() });
+    }
+
+    let size = attachments.iter().map(|attachment| attachment.file.len()).sum();
+    if size > ATTACHMENT_SIZE {
+        return Err(AttachmentError::TooLarge { size });
+    }
+
+    Ok(())
+}
+
+impl<'ctx, D: Sync> IntoFuture for ResponseBuilder<'ctx, D> {
+    type Output = Result<(), ResponseError>;
+    type IntoFuture = BoxFuture<'ctx, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
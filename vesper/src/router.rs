@@ -0,0 +1,24 @@
+use crate::hook::ComponentHook;
+use std::collections::HashMap;
+use crate::hash::BuildNameHasher;
+
+/// The separator between the prefix of a custom id and its payload.
+pub const CUSTOM_ID_SEPARATOR: char = ':';
+
+/// A map of [component hooks](ComponentHook) by the custom id prefix they handle.
+pub type ComponentMap<D, T, E> = HashMap<&'static str, ComponentHook<D, T, E>, BuildNameHasher>;
+
+/// Splits the given custom id into its prefix and its payload, if the custom id has no
+/// separator, the whole custom id is used as the prefix and the payload is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::router::split_custom_id;
+///
+/// assert_eq!(split_custom_id("page:5"), ("page", "5"));
+/// assert_eq!(split_custom_id("delete"), ("delete", ""));
+/// ```
+pub fn split_custom_id(custom_id: &str) -> (&str, &str) {
+    custom_id.split_once(CUSTOM_ID_SEPARATOR).unwrap_or((custom_id, ""))
+}
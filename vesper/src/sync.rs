@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use crate::{
+    command::Command,
+    framework::Framework,
+    group::GroupParent,
+    twilight_exports::{
+        ApplicationMarker, Command as TwilightCommand, CommandOption, CommandType, GuildMarker, Id,
+        Permissions
+    },
+};
+
+/// A summary of the changes made while [syncing](Framework::sync_commands) the commands of the
+/// framework.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncSummary {
+    /// The amount of commands that were not registered and have been created.
+    pub created: usize,
+    /// The amount of commands that were registered but changed, so they have been updated.
+    pub updated: usize,
+    /// The amount of registered commands that are no longer provided to the framework, so they
+    /// have been deleted.
+    pub deleted: usize,
+    /// The amount of commands that didn't change.
+    pub unchanged: usize,
+}
+
+impl SyncSummary {
+    /// Records the state of the given definition, removing the command it refers to from the
+    /// registered ones and returning whether if the command must be registered.
+    pub(crate) fn record(
+        &mut self,
+        definition: &CommandDefinition,
+        registered: &mut Vec<TwilightCommand>
+    ) -> bool {
+        match registered.iter().position(|command| definition.refers_to(command)) {
+            Some(position) => {
+                let command = registered.swap_remove(position);
+                if definition.matches(&command) {
+                    self.unchanged += 1;
+                    false
+                } else {
+                    self.updated += 1;
+                    true
+                }
+            },
+            None => {
+                self.created += 1;
+                true
+            }
+        }
+    }
+}
+
+/// The definition of a command as it would be registered, used to compare it against the ones
+/// already registered.
+pub(crate) struct CommandDefinition {
+    name: String,
+    kind: CommandType,
+    description: String,
+    name_localizations: Option<HashMap<String, String>>,
+    description_localizations: Option<HashMap<String, String>>,
+    options: Vec<CommandOption>,
+    default_member_permissions: Option<Permissions>,
+    dm_permission: bool,
+    nsfw: bool,
+}
+
+impl CommandDefinition {
+    pub(crate) fn from_command<D, T, E>(framework: &Framework<D, T, E>, command: &Command<D, T, E>) -> Self {
+        let chat = command.kind == CommandType::ChatInput;
+
+        Self {
+            name: command.name.to_string(),
+            kind: command.kind,
+            // Only chat commands have descriptions, options and localized descriptions.
+            description: if chat { command.description.to_string() } else { String::new() },
+            name_localizations: command.localized_names.get_localizations(framework, command),
+            description_localizations: if chat {
+                command.localized_descriptions.get_localizations(framework, command)
+            } else {
+                None
+            },
+            options: if chat {
+                command.arguments.iter()
+                    .flat_map(|a| a.as_options(framework, command))
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            default_member_permissions: command.required_permissions,
+            dm_permission: !command.only_guilds,
+            nsfw: command.nsfw,
+        }
+    }
+
+    pub(crate) fn from_group<D, T, E>(framework: &Framework<D, T, E>, group: &GroupParent<D, T, E>) -> Self {
+        Self {
+            name: group.name.to_string(),
+            kind: CommandType::ChatInput,
+            description: group.description.to_string(),
+            name_localizations: group.localized_names.clone(),
+            description_localizations: group.localized_descriptions.clone(),
+            options: group.get_options(framework),
+            default_member_permissions: group.required_permissions,
+            dm_permission: !group.only_guilds,
+            nsfw: group.nsfw,
+        }
+    }
+
+    /// Returns the options of the command.
+    pub(crate) fn options(&self) -> &[CommandOption] {
+        &self.options
+    }
+
+    /// Converts the definition into the command discord would return after registering it,
+    /// without an id and with a placeholder version.
+    pub(crate) fn into_model(
+        self,
+        application_id: Id<ApplicationMarker>,
+        guild: Option<Id<GuildMarker>>
+    ) -> TwilightCommand {
+        TwilightCommand {
+            application_id: Some(application_id),
+            default_member_permissions: self.default_member_permissions,
+            dm_permission: Some(self.dm_permission),
+            description: self.description,
+            description_localizations: self.description_localizations,
+            guild_id: guild,
+            id: None,
+            kind: self.kind,
+            name: self.name,
+            name_localizations: self.name_localizations,
+            nsfw: Some(self.nsfw),
+            options: self.options,
+            version: Id::new(1),
+        }
+    }
+
+    /// Returns whether the given registered command refers to this command, even if it changed.
+    pub(crate) fn refers_to(&self, command: &TwilightCommand) -> bool {
+        self.name == command.name && self.kind == command.kind
+    }
+
+    /// Returns whether the given registered command is equal to this definition.
+    pub(crate) fn matches(&self, command: &TwilightCommand) -> bool {
+        self.refers_to(command)
+            && self.description == command.description
+            && non_empty(&self.name_localizations) == non_empty(&command.name_localizations)
+            && non_empty(&self.description_localizations) == non_empty(&command.description_localizations)
+            && self.default_member_permissions == command.default_member_permissions
+            && self.dm_permission == command.dm_permission.unwrap_or(true)
+            && self.nsfw == command.nsfw.unwrap_or(false)
+            && normalize_options(&self.options) == normalize_options(&command.options)
+    }
+}
+
+fn non_empty(map: &Option<HashMap<String, String>>) -> Option<&HashMap<String, String>> {
+    map.as_ref().filter(|map| !map.is_empty())
+}
+
+/// Normalizes the given options, so missing and default values compare as equal, as discord
+/// omits them when returning registered commands.
+fn normalize_options(options: &[CommandOption]) -> Vec<CommandOption> {
+    options.iter()
+        .cloned()
+        .map(|mut option| {
+            option.required = option.required.filter(|required| *required);
+            option.autocomplete = option.autocomplete.filter(|autocomplete| *autocomplete);
+            option.choices = option.choices.filter(|choices| !choices.is_empty());
+            option.channel_types = option.channel_types.filter(|types| !types.is_empty());
+            option.name_localizations = option.name_localizations.filter(|map| !map.is_empty());
+            option.description_localizations = option.description_localizations.filter(|map| !map.is_empty());
+            option.options = option.options
+                .map(|options| normalize_options(&options))
+                .filter(|options| !options.is_empty());
+            option
+        })
+        .collect()
+}
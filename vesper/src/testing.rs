@@ -0,0 +1,457 @@
+//! Utilities used to invoke commands in tests, without connecting to discord.
+//!
+//! A [MockServer] stands in for discord's API, recording every request the framework sends, so
+//! tests can assert on the responses of commands. Interactions are built using
+//! [TestInteraction], which are then processed by a framework using the
+//! [client](MockServer::client) of the server.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use vesper::{command::ExecutionState, prelude::*, testing::{MockServer, TestInteraction}};
+//! use vesper::twilight_exports::{CommandOptionValue, Id};
+//!
+//! #[command]
+//! #[description = "Says hello"]
+//! async fn hello(ctx: &SlashContext<()>, #[description = "Who to greet"] name: String) -> DefaultCommandResult {
+//!     ctx.respond().content(format!("Hello, {}!", name)).await?;
+//!     Ok(())
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = MockServer::start().await.unwrap();
+//!     let framework = Framework::builder(server.client(), Id::new(1), ())
+//!         .command(hello)
+//!         .build();
+//!
+//!     let result = TestInteraction::new("hello")
+//!         .option("name", CommandOptionValue::String(String::from("vesper")))
+//!         .execute(&framework)
+//!         .await
+//!         .unwrap();
+//!
+//!     assert!(matches!(result.state, ExecutionState::CommandFinished));
+//!     assert_eq!(server.requests()[0].content().as_deref(), Some("Hello, vesper!"));
+//! }
+//! ```
+
+use crate::{
+    command::ExecutionResult,
+    context::DISCORD_EPOCH,
+    framework::{Framework, ProcessResult},
+    parse::ParseError,
+    twilight_exports::{
+        ChannelMarker, CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+        CommandType, GuildMarker, Id, Interaction, Permissions, UserMarker
+    },
+};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::{io::Result as IoResult, net::SocketAddr, sync::Arc, time::{SystemTime, UNIX_EPOCH}};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use twilight_http::Client;
+
+/// The prefix of every route of discord's API.
+const API_PREFIX: &str = "/api/v10";
+
+/// A request sent to a [MockServer].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// The method of the request.
+    pub method: String,
+    /// The path of the request, without the `/api/v10` prefix and the query string.
+    pub path: String,
+    /// The raw body of the request.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Parses the body of the request as JSON, returning `None` if it isn't valid JSON, like the
+    /// multipart bodies used when uploading attachments.
+    pub fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+
+    /// Returns the content of the message sent by the request, looking both at interaction
+    /// callbacks and at message edits and followups.
+    pub fn content(&self) -> Option<String> {
+        let json = self.json()?;
+        json.pointer("/data/content")
+            .or_else(|| json.get("content"))
+            .and_then(Value::as_str)
+            .map(String::from)
+    }
+
+    /// Returns whether the request is an interaction callback, used to initially respond to an
+    /// interaction.
+    pub fn is_callback(&self) -> bool {
+        self.path.starts_with("/interactions/") && self.path.ends_with("/callback")
+    }
+}
+
+/// A tiny HTTP server mocking discord's API, recording every request it receives.
+///
+/// Interaction callbacks are answered with an empty response, and requests registering commands
+/// are answered with the registered commands, so the registration payload can be inspected. The
+/// rest of the requests are answered with a message containing the content of the request, so
+/// responses can also be edited and followed up. Requests expecting anything else than a message
+/// can be sent, but deserializing their response fails.
+///
+/// The server runs until it is dropped.
+pub struct MockServer {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Starts a new server listening on a random local port.
+    pub async fn start() -> IoResult<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let address = listener.local_addr()?;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let task = tokio::spawn(serve(listener, Arc::clone(&requests)));
+
+        Ok(Self {
+            address,
+            requests,
+            task,
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Creates an http client sending its requests to this server, which can be provided to a
+    /// [framework builder](crate::builder::FrameworkBuilder).
+    pub fn client(&self) -> Client {
+        Client::builder()
+            .token(String::from("Bot vesper-testing"))
+            .proxy(self.address.to_string(), true)
+            .ratelimiter(None)
+            .build()
+    }
+
+    /// Returns the requests received so far, in the order they were received.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().clone()
+    }
+
+    /// Returns the content of every message sent so far, in the order they were sent.
+    pub fn contents(&self) -> Vec<String> {
+        self.requests.lock()
+            .iter()
+            .filter_map(RecordedRequest::content)
+            .collect()
+    }
+
+    /// Removes all the requests recorded so far.
+    pub fn clear(&self) {
+        self.requests.lock().clear();
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Accepts connections, handling each one of them in a separate task.
+async fn serve(listener: TcpListener, requests: Arc<Mutex<Vec<RecordedRequest>>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_connection(stream, Arc::clone(&requests)));
+    }
+}
+
+/// Reads requests from the given connection until it is closed, recording and answering them.
+async fn handle_connection(mut stream: TcpStream, requests: Arc<Mutex<Vec<RecordedRequest>>>) -> IoResult<()> {
+    let mut buffer = Vec::new();
+
+    loop {
+        let header_end = loop {
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+
+            if !read_more(&mut stream, &mut buffer).await? {
+                return Ok(());
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+        let mut lines = head.split("\r\n");
+        let mut request_line = lines.next().unwrap_or_default().split(' ');
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().split('?').next().unwrap_or_default();
+        let path = path.strip_prefix(API_PREFIX).unwrap_or(path).to_string();
+
+        let length = lines
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+
+        while buffer.len() < header_end + length {
+            if !read_more(&mut stream, &mut buffer).await? {
+                return Ok(());
+            }
+        }
+
+        let body = buffer.drain(..header_end + length).skip(header_end).collect::<Vec<_>>();
+        let request = RecordedRequest { method, path, body };
+        let response = respond(&request);
+        requests.lock().push(request);
+
+        stream.write_all(response.as_bytes()).await?;
+    }
+}
+
+/// Reads more bytes from the stream into the buffer, returning false if the connection was closed.
+async fn read_more(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> IoResult<bool> {
+    let mut chunk = [0; 4096];
+    let read = stream.read(&mut chunk).await?;
+    buffer.extend_from_slice(&chunk[..read]);
+
+    Ok(read != 0)
+}
+
+/// Builds the raw HTTP response to the given request.
+fn respond(request: &RecordedRequest) -> String {
+    if request.is_callback() {
+        return String::from("HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n");
+    }
+
+    if request.path.starts_with("/applications/") && request.path.contains("/commands") {
+        if let Some(commands) = request.json() {
+            return ok(registered(commands));
+        }
+    }
+
+    ok(json!({
+        "id": "1",
+        "channel_id": "1",
+        "author": {
+            "id": "1",
+            "username": "vesper",
+            "discriminator": "0000",
+            "avatar": null,
+            "bot": true
+        },
+        "content": request.content().unwrap_or_default(),
+        "timestamp": "2015-01-01T00:00:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0
+    }))
+}
+
+/// Gives the commands sent in a registration request the fields discord sets once they are
+/// registered.
+fn registered(commands: Value) -> Value {
+    match commands {
+        Value::Array(commands) => commands.into_iter().map(registered).collect(),
+        Value::Object(mut command) => {
+            command.entry("id").or_insert_with(|| json!("1"));
+            command.entry("version").or_insert_with(|| json!("1"));
+            Value::Object(command)
+        },
+        other => other
+    }
+}
+
+/// Builds a successful raw HTTP response with the given JSON body.
+fn ok(body: Value) -> String {
+    let body = body.to_string();
+
+    format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+/// A builder used to create command interactions, which can be processed by a framework as if
+/// they were sent by discord.
+#[derive(Debug, Clone)]
+pub struct TestInteraction {
+    name: String,
+    kind: CommandType,
+    options: Vec<CommandDataOption>,
+    resolved: Option<CommandInteractionDataResolved>,
+    user: Id<UserMarker>,
+    channel: Id<ChannelMarker>,
+    guild: Option<Id<GuildMarker>>,
+    permissions: Permissions,
+    locale: String,
+}
+
+impl TestInteraction {
+    /// Creates a new interaction invoking the command with the given name.
+    ///
+    /// Subcommands are invoked by separating the names of the groups and the subcommand with
+    /// spaces, like `"config set"`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: CommandType::ChatInput,
+            options: Vec::new(),
+            resolved: None,
+            user: Id::new(1),
+            channel: Id::new(1),
+            guild: None,
+            permissions: Permissions::all(),
+            locale: String::from("en-US"),
+        }
+    }
+
+    /// Sets the type of the invoked command, defaults to a chat command.
+    pub fn kind(mut self, kind: CommandType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Adds an argument with the given name and value.
+    pub fn option(mut self, name: impl Into<String>, value: CommandOptionValue) -> Self {
+        self.options.push(CommandDataOption {
+            name: name.into(),
+            value,
+        });
+        self
+    }
+
+    /// Sets the resolved data of the interaction, containing the users, roles, channels and
+    /// attachments referenced by the arguments.
+    pub fn resolved(mut self, resolved: CommandInteractionDataResolved) -> Self {
+        self.resolved = Some(resolved);
+        self
+    }
+
+    /// Sets the user invoking the command.
+    pub fn user(mut self, user: Id<UserMarker>) -> Self {
+        self.user = user;
+        self
+    }
+
+    /// Sets the channel the command is invoked in.
+    pub fn channel(mut self, channel: Id<ChannelMarker>) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Sets the guild the command is invoked in, the command is invoked in direct messages if no
+    /// guild is set.
+    pub fn guild(mut self, guild: Id<GuildMarker>) -> Self {
+        self.guild = Some(guild);
+        self
+    }
+
+    /// Sets the permissions of the user in the channel the command is invoked in, defaults to
+    /// all permissions. Only used when the command is invoked in a guild.
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    /// Sets the locale of the user invoking the command, defaults to `en-US`.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Builds the interaction.
+    pub fn interaction(&self) -> Interaction {
+        let mut names = self.name.split_whitespace().rev();
+        let mut options = self.options.clone();
+        let mut command = names.next().unwrap_or_default().to_string();
+
+        // Nest the options inside each subcommand and group, from the innermost to the outermost.
+        for (depth, parent) in names.enumerate() {
+            let option = CommandDataOption {
+                name: command,
+                value: if depth == 0 {
+                    CommandOptionValue::SubCommand(options)
+                } else {
+                    CommandOptionValue::SubCommandGroup(options)
+                },
+            };
+
+            options = vec![option];
+            command = parent.to_string();
+        }
+
+        let user = json!({
+            "id": self.user,
+            "username": "test",
+            "discriminator": "0000",
+            "avatar": null
+        });
+
+        // Use an id created now, so the token of the interaction isn't expired.
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64)
+            .saturating_sub(DISCORD_EPOCH);
+
+        let mut interaction = json!({
+            "id": (created << 22).max(1).to_string(),
+            "application_id": "1",
+            "type": 2,
+            "token": "vesper-testing",
+            "version": 1,
+            "locale": self.locale,
+            "channel": { "id": self.channel, "type": if self.guild.is_some() { 0 } else { 1 } },
+            "channel_id": self.channel,
+            "data": {
+                "id": "1",
+                "name": command,
+                "type": u8::from(self.kind),
+                "options": options,
+                "resolved": self.resolved,
+            }
+        });
+
+        match self.guild {
+            Some(guild) => {
+                interaction["guild_id"] = json!(guild);
+                interaction["guild_locale"] = json!(self.locale);
+                interaction["member"] = json!({
+                    "user": user,
+                    "roles": [],
+                    "joined_at": "2015-01-01T00:00:00.000000+00:00",
+                    "deaf": false,
+                    "mute": false,
+                    "flags": 0,
+                    "permissions": self.permissions
+                });
+            },
+            None => interaction["user"] = user
+        }
+
+        serde_json::from_value(interaction).expect("Test interactions are always valid")
+    }
+
+    /// Processes the interaction using the given framework, returning the result of the command
+    /// execution, or `None` if the command wasn't found.
+    pub async fn execute<D, T, E>(&self, framework: &Framework<D, T, E>) -> Option<ExecutionResult<T, E>>
+    where
+        E: From<ParseError>
+    {
+        match framework.process(self.interaction()).await {
+            ProcessResult::CommandExecuted(result) => Some(result),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::any::type_name;
+use crate::prelude::*;
+use crate::twilight_exports::*;
+use crate::parse_impl::out_of_range;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// A type used to collect a variable amount of values of the same type, up to `MAX` values.
+///
+/// Since discord does not support variadic options, the argument is registered as `MAX` options
+/// named `<name>_1`, `<name>_2`... up to `<name>_MAX`, where only the first one is required if `T`
+/// is required. When parsing, all the provided options are collected in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "Gives some items"]
+/// async fn give(
+///     ctx: &mut SlashContext<()>,
+///     #[description = "The items to give"] item: Variadic<String, 5>
+/// ) -> DefaultCommandResult {
+///     // The items are available in the order they were registered, `item_1`, `item_2`...
+///     for item in item.iter() {
+///         println!("Giving {item}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Variadic<T, const MAX: usize>(Vec<T>);
+
+impl<T, const MAX: usize> Variadic<T, MAX> {
+    /// Returns the collected values.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const MAX: usize> Deref for Variadic<T, MAX> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const MAX: usize> DerefMut for Variadic<T, MAX> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T, E, const MAX: usize> Parse<T> for Variadic<E, MAX>
+where
+    T: Send + Sync,
+    E: Parse<T>
+{
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        Self::parse_variadic(http_client, data, value.into_iter().collect(), resolved).await
+    }
+
+    fn kind() -> CommandOptionType {
+        E::kind()
+    }
+
+    fn required() -> bool {
+        E::required()
+    }
+
+    fn choices() -> Option<Vec<CommandOptionChoice>> {
+        E::choices()
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        E::modify_option(option)
+    }
+
+    fn variadic() -> Option<usize> {
+        Some(MAX)
+    }
+
+    async fn parse_variadic(
+        http_client: &WrappedClient,
+        data: &T,
+        values: Vec<&CommandOptionValue>,
+        mut resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        if values.len() > MAX {
+            return Err(out_of_range(
+                &format!("Variadic<{}, {}>", type_name::<E>(), MAX),
+                &format!("At most {} values can be provided", MAX)
+            ));
+        }
+
+        let mut parsed = Vec::with_capacity(values.len());
+
+        for (index, value) in values.into_iter().enumerate() {
+            let item = E::parse(http_client, data, Some(value), resolved.as_deref_mut()).await
+                .map_err(|mut why| {
+                    if let ParseError::OutOfRange { error, .. } = &mut why {
+                        *error = format!("Value at position {} failed to parse: {}", index + 1, error);
+                    }
+                    why
+                })?;
+
+            parsed.push(item);
+        }
+
+        Ok(Self(parsed))
+    }
+}
+
+impl<T: Debug, const MAX: usize> Debug for Variadic<T, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Variadic<{}, {}>({:?})", type_name::<T>(), MAX, self.0)
+    }
+}
+
+/// A [variadic](Variadic) argument collecting its values into a [HashSet], removing the
+/// duplicated ones, useful for arguments where repeated values are meaningless, such as a set of
+/// users or roles.
+///
+/// If `STRICT` is `true`, providing the same value more than once fails parsing instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use vesper::prelude::*;
+/// use vesper::twilight_exports::{Id, UserMarker};
+///
+/// #[command]
+/// #[description = "Mentions some users"]
+/// async fn mention(
+///     ctx: &mut SlashContext<()>,
+///     #[description = "The users to mention"] user: Unique<Id<UserMarker>, 5>
+/// ) -> DefaultCommandResult {
+///     for user in user.iter() {
+///         println!("Mentioning <@{user}>");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Unique<T, const MAX: usize, const STRICT: bool = false>(HashSet<T>);
+
+impl<T, const MAX: usize, const STRICT: bool> Unique<T, MAX, STRICT> {
+    /// Returns the collected values.
+    pub fn into_inner(self) -> HashSet<T> {
+        self.0
+    }
+}
+
+impl<T, const MAX: usize, const STRICT: bool> Deref for Unique<T, MAX, STRICT> {
+    type Target = HashSet<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const MAX: usize, const STRICT: bool> DerefMut for Unique<T, MAX, STRICT> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl<T, E, const MAX: usize, const STRICT: bool> Parse<T> for Unique<E, MAX, STRICT>
+where
+    T: Send + Sync,
+    E: Parse<T> + Eq + Hash
+{
+    async fn parse(
+        http_client: &WrappedClient,
+        data: &T,
+        value: Option<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        Self::parse_variadic(http_client, data, value.into_iter().collect(), resolved).await
+    }
+
+    fn kind() -> CommandOptionType {
+        E::kind()
+    }
+
+    fn required() -> bool {
+        E::required()
+    }
+
+    fn choices() -> Option<Vec<CommandOptionChoice>> {
+        E::choices()
+    }
+
+    fn modify_option(option: &mut CommandOption) {
+        E::modify_option(option)
+    }
+
+    fn variadic() -> Option<usize> {
+        Some(MAX)
+    }
+
+    async fn parse_variadic(
+        http_client: &WrappedClient,
+        data: &T,
+        values: Vec<&CommandOptionValue>,
+        resolved: Option<&mut CommandInteractionDataResolved>
+    ) -> Result<Self, ParseError> {
+        let values = Variadic::<E, MAX>::parse_variadic(http_client, data, values, resolved).await?
+            .into_inner();
+        let amount = values.len();
+        let set = values.into_iter().collect::<HashSet<_>>();
+
+        if STRICT && set.len() != amount {
+            return Err(out_of_range(
+                &format!("Unique<{}, {}>", type_name::<E>(), MAX),
+                "The same value was provided more than once"
+            ));
+        }
+
+        Ok(Self(set))
+    }
+}
+
+impl<T: Debug, const MAX: usize, const STRICT: bool> Debug for Unique<T, MAX, STRICT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Unique<{}, {}>({:?})", type_name::<T>(), MAX, self.0)
+    }
+}
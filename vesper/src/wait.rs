@@ -1,6 +1,10 @@
 use std::{future::Future, task::{Context, Poll}};
 use std::pin::Pin;
+use std::time::Duration;
+use futures_core::Stream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 use tokio::sync::oneshot::{Sender, Receiver, channel, error::RecvError};
+use tokio::time::{sleep, Sleep};
 use crate::twilight_exports::Interaction;
 
 pub(crate) fn new_pair<F>(fun: F) -> (WaiterWaker, InteractionWaiter)
@@ -12,7 +16,7 @@ where
     (
         WaiterWaker {
             predicate: Box::new(fun),
-            sender
+            sender: WakerSender::Once(sender)
         },
         InteractionWaiter {
             receiver
@@ -20,6 +24,24 @@ where
     )
 }
 
+pub(crate) fn new_stream_pair<F>(fun: F) -> (WaiterWaker, InteractionStream)
+where
+    F: Fn(&Interaction) -> bool + Send + 'static
+{
+    let (sender, receiver) = unbounded_channel();
+
+    (
+        WaiterWaker {
+            predicate: Box::new(fun),
+            sender: WakerSender::Stream(sender)
+        },
+        InteractionStream {
+            receiver,
+            deadline: None
+        }
+    )
+}
+
 /// A waiter used to wait for an interaction.
 ///
 /// The waiter implements [`Future`], so in order to retrieve the interaction, just await the waiter.
@@ -32,7 +54,7 @@ where
 /// #[command]
 /// #[description = "My Command"]
 /// async fn my_command(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
-///     ctx.defer(false).await?;
+///     ctx.defer().await?;
 ///     let interaction = ctx.wait_interaction(|interaction| {
 ///         // predicate here
 ///         false
@@ -47,6 +69,21 @@ pub struct InteractionWaiter {
     receiver: Receiver<Interaction>
 }
 
+impl InteractionWaiter {
+    /// Waits for the interaction up to the given duration, returning `Ok(None)` if the duration
+    /// elapses before the interaction is received.
+    ///
+    /// Once the waiter times out, it is dropped and stops receiving interactions. Its waker stays in
+    /// the framework until the next interaction is delivered to the waiters or a new waiter is
+    /// created, when the wakers of dropped waiters are removed.
+    pub async fn timeout(self, duration: Duration) -> Result<Option<Interaction>, RecvError> {
+        match tokio::time::timeout(duration, self).await {
+            Ok(result) => result.map(Some),
+            Err(_) => Ok(None)
+        }
+    }
+}
+
 impl Future for InteractionWaiter {
     type Output = Result<Interaction, RecvError>;
 
@@ -54,7 +91,77 @@ impl Future for InteractionWaiter {
         Pin::new(&mut self.receiver).poll(cx)
     }
 }
+/// A stream used to receive all the interactions satisfying a predicate, such as the button
+/// presses of a paginator.
+///
+/// The stream yields interactions until it times out, [stop](Self::stop) is called or it is
+/// dropped. Its waker is then removed from the framework the next time an interaction is
+/// delivered to the waiters or a new waiter is created.
+///
+/// # Examples:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use vesper::prelude::*;
+///
+/// #[command]
+/// #[description = "My Command"]
+/// async fn my_command(ctx: &mut SlashContext<()>) -> DefaultCommandResult {
+///     let mut stream = ctx.wait_component_stream(|interaction| {
+///         // predicate here
+///         false
+///     }).timeout(Duration::from_secs(60));
+///
+///     while let Some(interaction) = stream.next().await {
+///         // Handle the interaction...
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct InteractionStream {
+    receiver: UnboundedReceiver<Interaction>,
+    deadline: Option<Pin<Box<Sleep>>>
+}
+
+impl InteractionStream {
+    /// Sets the duration after which the stream stops yielding interactions.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.deadline = Some(Box::pin(sleep(duration)));
+        self
+    }
+
+    /// Stops the stream, no more interactions will be received, but the ones already received
+    /// will still be yielded.
+    pub fn stop(&mut self) {
+        self.receiver.close();
+    }
+}
+
+impl Stream for InteractionStream {
+    type Item = Interaction;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(deadline) = self.deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                self.deadline = None;
+                self.stop();
+                return Poll::Ready(None);
+            }
+        }
+
+        self.receiver.poll_recv(cx)
+    }
+}
 
+/// The sender used to deliver interactions to a waiter.
+pub(crate) enum WakerSender {
+    /// The waiter only receives a single interaction.
+    Once(Sender<Interaction>),
+    /// The waiter receives interactions until it is closed.
+    Stream(UnboundedSender<Interaction>)
+}
 
 /// A waker used to notify its associate [`waiter`] when the predicate has been satisfied and
 /// deliver the interaction.
@@ -62,7 +169,7 @@ impl Future for InteractionWaiter {
 /// [`waiter`]: InteractionWaiter
 pub struct WaiterWaker {
     pub predicate: Box<dyn Fn(&Interaction) -> bool + Send + 'static>,
-    pub sender: Sender<Interaction>
+    pub(crate) sender: WakerSender
 }
 
 impl WaiterWaker {
@@ -70,7 +177,26 @@ impl WaiterWaker {
         (self.predicate)(interaction)
     }
 
-    pub fn wake(self, interaction: Interaction) {
-        let _ = self.sender.send(interaction);
+    /// Delivers the interaction to the waiter, returning the waker back if the waiter keeps
+    /// waiting for more interactions.
+    pub fn wake(self, interaction: Interaction) -> Option<Self> {
+        match self.sender {
+            WakerSender::Once(sender) => {
+                let _ = sender.send(interaction);
+                None
+            },
+            WakerSender::Stream(ref sender) => {
+                let _ = sender.send(interaction);
+                Some(self)
+            }
+        }
+    }
+
+    /// Returns whether the associated waiter has been dropped, so this waker is no longer needed.
+    pub fn is_closed(&self) -> bool {
+        match &self.sender {
+            WakerSender::Once(sender) => sender.is_closed(),
+            WakerSender::Stream(sender) => sender.is_closed()
+        }
     }
 }
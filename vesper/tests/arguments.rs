@@ -0,0 +1,175 @@
+use vesper::{error::ValidationError, prelude::*, testing::MockServer};
+use vesper::twilight_exports::Id;
+
+#[command]
+#[description = "Gives some items"]
+async fn give(
+    _ctx: &SlashContext<()>,
+    #[description = "The items to give"] item: Variadic<String, 3>,
+    #[description = "Who receives the items"] target: String,
+    #[description = "A message sent along the items"] message: Option<String>
+) -> DefaultCommandResult {
+    drop((item, target, message));
+    Ok(())
+}
+
+#[command]
+#[description = "Swaps some items"]
+async fn swap(
+    _ctx: &SlashContext<()>,
+    #[description = "The items given"] given: Variadic<String, 2>,
+    #[description = "The items received"] received: Variadic<String, 2>
+) -> DefaultCommandResult {
+    drop((given, received));
+    Ok(())
+}
+
+#[tokio::test]
+async fn required_variadic_is_registered_after_required_arguments() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(give)
+        .build();
+
+    framework.register_global_commands().await.unwrap();
+
+    let command = server.requests()[0].json().unwrap();
+    let options = command["options"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|option| (option["name"].as_str().unwrap(), option["required"].as_bool().unwrap()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(options, [
+        ("target", true),
+        ("item_1", true),
+        ("item_2", false),
+        ("item_3", false),
+        ("message", false)
+    ]);
+}
+
+#[tokio::test]
+async fn required_argument_after_variadic_is_rejected() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(give)
+        .command(swap)
+        .build();
+
+    let errors = framework.validate();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::RequiredAfterOptional { command, argument } if command == "swap" && argument == "received"
+    ));
+}
+
+#[command]
+#[description = "Tags a message"]
+async fn tag(
+    _ctx: &SlashContext<()>,
+    #[description = "The tags to add"] tag: Variadic<String, 3>,
+    #[description = "The first tag"] tag_1: Option<String>
+) -> DefaultCommandResult {
+    drop((tag, tag_1));
+    Ok(())
+}
+
+#[command]
+#[description = "Takes too many values"]
+async fn collect(
+    _ctx: &SlashContext<()>,
+    #[description = "The values"] values: Variadic<String, 25>,
+    #[description = "A note"] note: Option<String>
+) -> DefaultCommandResult {
+    drop((values, note));
+    Ok(())
+}
+
+#[command]
+#[description = "Has a long variadic argument"]
+async fn long(
+    _ctx: &SlashContext<()>,
+    #[description = "The values"] an_argument_named_with_30chars: Variadic<String, 10>
+) -> DefaultCommandResult {
+    drop(an_argument_named_with_30chars);
+    Ok(())
+}
+
+#[tokio::test]
+async fn expanded_variadic_options_are_validated() {
+    let server = MockServer::start().await.unwrap();
+
+    let errors = Framework::builder(server.client(), Id::new(1), ())
+        .command(tag)
+        .build()
+        .validate();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::DuplicateArgument { command, argument } if command == "tag" && argument == "tag_1"
+    ));
+
+    let errors = Framework::builder(server.client(), Id::new(1), ())
+        .command(collect)
+        .build()
+        .validate();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::TooManyOptions { command, amount: 26 } if command == "collect"
+    ));
+
+    let errors = Framework::builder(server.client(), Id::new(1), ())
+        .command(long)
+        .build()
+        .validate();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::InvalidArgumentName { command, argument, .. }
+            if command == "long" && argument == "an_argument_named_with_30chars_10"
+    ));
+}
+
+#[command]
+#[description = "Edits a role"]
+async fn edit(
+    _ctx: &SlashContext<()>,
+    #[description = "The role to edit"] role: String,
+    #[description = "The new name"] name: Option<String>,
+    #[description = "The new color"] color: Option<String>
+) -> DefaultCommandResult {
+    drop((role, name, color));
+    Ok(())
+}
+
+#[tokio::test]
+async fn require_one_of_arguments_added_through_the_builder_are_validated() {
+    let server = MockServer::start().await.unwrap();
+
+    let errors = Framework::builder(server.client(), Id::new(1), ())
+        .command(|| edit().require_one_of(["name", "color"]))
+        .build()
+        .validate();
+    assert!(errors.is_empty());
+
+    let errors = Framework::builder(server.client(), Id::new(1), ())
+        .command(|| edit().require_one_of(["name", "colour", "role"]))
+        .build()
+        .validate();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        &errors[0],
+        ValidationError::InvalidRequiredArgument { command, argument, reason: "does not exist" }
+            if command == "edit" && argument == "colour"
+    ));
+    assert!(matches!(
+        &errors[1],
+        ValidationError::InvalidRequiredArgument { command, argument, reason: "is already required" }
+            if command == "edit" && argument == "role"
+    ));
+}
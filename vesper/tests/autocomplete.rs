@@ -0,0 +1,49 @@
+use vesper::{framework::ProcessResult, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{
+    CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType, CommandOptionValue, Id,
+    InteractionResponseData, InteractionType
+};
+
+#[autocomplete]
+async fn suggest_item(ctx: AutocompleteContext<()>) -> Option<InteractionResponseData> {
+    Some(InteractionResponseData {
+        choices: Some(vec![CommandOptionChoice {
+            name: format!("{} sword", ctx.user_input.input),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(String::from("sword"))
+        }]),
+        ..Default::default()
+    })
+}
+
+#[command]
+#[description = "Gives some items"]
+async fn give(
+    _ctx: &SlashContext<()>,
+    #[autocomplete(suggest_item)] #[description = "The items to give"] item: Variadic<String, 3>
+) -> DefaultCommandResult {
+    drop(item);
+    Ok(())
+}
+
+#[tokio::test]
+async fn variadic_options_are_autocompleted() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(give)
+        .build();
+
+    for option in ["item_1", "item_3"] {
+        server.clear();
+
+        let mut interaction = TestInteraction::new("give")
+            .option(option, CommandOptionValue::Focused(String::from("Iron"), CommandOptionType::String))
+            .interaction();
+        interaction.kind = InteractionType::ApplicationCommandAutocomplete;
+
+        assert!(matches!(framework.process(interaction).await, ProcessResult::Autocompleted));
+
+        let response = server.requests()[0].json().unwrap();
+        assert_eq!(response["data"]["choices"][0]["name"], "Iron sword");
+    }
+}
@@ -0,0 +1,140 @@
+use serde_json::json;
+use std::sync::Mutex;
+use vesper::{command::{ExecutionState, OutputLocation}, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{CommandOptionValue, Id};
+
+#[derive(Debug, Clone, Copy, PartialEq, Choice)]
+enum Speed {
+    #[choice(value = 1)]
+    Slow,
+    #[choice(name = "Very fast", value = 10)]
+    Fast
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Choice)]
+enum Ratio {
+    #[choice(value = 0.5)]
+    Half,
+    #[choice(value = 2.0)]
+    Double
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Choice)]
+enum Size {
+    #[choice(name_localizations("en-US" = "Small", "fr" = "Petit"))]
+    Small,
+    #[choice(name_localizations("en-US" = "Large", "fr" = "Grand"))]
+    Large
+}
+
+/// The choices received by the commands.
+#[derive(Default)]
+struct Received {
+    speeds: Mutex<Vec<Speed>>,
+    ratios: Mutex<Vec<Ratio>>
+}
+
+#[command]
+#[description = "Sets the speed"]
+async fn speed(ctx: &SlashContext<Received>, #[description = "The speed"] speed: Speed) -> DefaultCommandResult {
+    ctx.data.speeds.lock().unwrap().push(speed);
+    Ok(())
+}
+
+#[command]
+#[description = "Sets the ratio"]
+async fn ratio(ctx: &SlashContext<Received>, #[description = "The ratio"] ratio: Ratio) -> DefaultCommandResult {
+    ctx.data.ratios.lock().unwrap().push(ratio);
+    Ok(())
+}
+
+#[command]
+#[description = "Sets the size"]
+async fn size(_ctx: &SlashContext<Received>, #[description = "The size"] _size: Size) -> DefaultCommandResult {
+    Ok(())
+}
+
+fn framework(server: &MockServer) -> Framework<Received> {
+    Framework::builder(server.client(), Id::new(1), Received::default())
+        .command(speed)
+        .command(ratio)
+        .command(size)
+        .build()
+}
+
+#[tokio::test]
+async fn integer_choices_dispatch_each_variant() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    for value in [1, 10] {
+        let result = TestInteraction::new("speed")
+            .option("speed", CommandOptionValue::Integer(value))
+            .execute(&framework)
+            .await
+            .unwrap();
+
+        assert!(matches!(result.state, ExecutionState::CommandFinished));
+    }
+
+    assert_eq!(*framework.data.speeds.lock().unwrap(), [Speed::Slow, Speed::Fast]);
+}
+
+#[tokio::test]
+async fn unknown_integer_choice_is_rejected() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let result = TestInteraction::new("speed")
+        .option("speed", CommandOptionValue::Integer(5))
+        .execute(&framework)
+        .await
+        .unwrap();
+
+    assert!(matches!(result.state, ExecutionState::CommandErrored));
+    let OutputLocation::Present(Err(error)) = &result.output else {
+        panic!("The command output is missing");
+    };
+    assert!(matches!(
+        error.downcast_ref::<ParseError>(),
+        Some(ParseError::OutOfRange { argument_name, .. }) if argument_name == "speed"
+    ));
+    assert!(framework.data.speeds.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn number_choices_dispatch_each_variant() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    for value in [0.5, 2.0] {
+        let result = TestInteraction::new("ratio")
+            .option("ratio", CommandOptionValue::Number(value))
+            .execute(&framework)
+            .await
+            .unwrap();
+
+        assert!(matches!(result.state, ExecutionState::CommandFinished));
+    }
+
+    assert_eq!(*framework.data.ratios.lock().unwrap(), [Ratio::Half, Ratio::Double]);
+}
+
+#[tokio::test]
+async fn choice_localizations_are_registered() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    framework.register_global_commands().await.unwrap();
+
+    let command = server.requests()
+        .iter()
+        .filter_map(|request| request.json())
+        .find(|command| command["name"] == "size")
+        .unwrap();
+
+    assert_eq!(command["options"][0]["choices"], json!([
+        { "name": "Small", "name_localizations": { "en-US": "Small", "fr": "Petit" }, "value": "Small" },
+        { "name": "Large", "name_localizations": { "en-US": "Large", "fr": "Grand" }, "value": "Large" }
+    ]));
+}
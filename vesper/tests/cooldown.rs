@@ -0,0 +1,70 @@
+use vesper::{command::ExecutionState, framework::ProcessResult, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{Id, Interaction};
+
+#[command]
+#[description = "Can be used once per channel"]
+#[cooldown(seconds = 30, bucket = "channel")]
+async fn channel(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command]
+#[description = "Can be used once per guild"]
+#[cooldown(seconds = 30, bucket = "guild")]
+async fn guild(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+fn framework(server: &MockServer) -> Framework<()> {
+    Framework::builder(server.client(), Id::new(1), ())
+        .command(channel)
+        .command(guild)
+        .build()
+}
+
+/// Processes the given interaction, returning whether the command was on cooldown.
+async fn on_cooldown(framework: &Framework<()>, interaction: Interaction) -> bool {
+    match framework.process(interaction).await {
+        ProcessResult::CommandExecuted(result) => matches!(result.state, ExecutionState::OnCooldown(_)),
+        _ => panic!("The command was not executed")
+    }
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn channel_cooldown_falls_back_to_the_channel_id() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let interaction = |channel| {
+        let mut interaction = TestInteraction::new("channel").channel(Id::new(channel)).interaction();
+        interaction.channel = None;
+        interaction
+    };
+
+    assert!(!on_cooldown(&framework, interaction(1)).await);
+    assert!(!on_cooldown(&framework, interaction(2)).await);
+    assert!(on_cooldown(&framework, interaction(1)).await);
+
+    // Without any channel id, the command is never put on cooldown.
+    for _ in 0..2 {
+        let mut interaction = interaction(3);
+        interaction.channel_id = None;
+        assert!(!on_cooldown(&framework, interaction).await);
+    }
+}
+
+#[tokio::test]
+async fn guild_cooldown_is_not_shared_in_direct_messages() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    for user in [1, 2, 1] {
+        let interaction = TestInteraction::new("guild").user(Id::new(user)).interaction();
+        assert!(!on_cooldown(&framework, interaction).await);
+    }
+
+    let interaction = || TestInteraction::new("guild").guild(Id::new(1)).interaction();
+    assert!(!on_cooldown(&framework, interaction()).await);
+    assert!(on_cooldown(&framework, interaction()).await);
+}
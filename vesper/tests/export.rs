@@ -0,0 +1,89 @@
+use serde_json::{json, Value};
+use vesper::{command::Command, framework::DefaultError, prelude::*, testing::MockServer};
+use vesper::twilight_exports::Id;
+
+#[command]
+#[description = "Works everywhere"]
+#[integration_types(guild_install, user_install)]
+#[contexts(guild, bot_dm, private_channel)]
+async fn everywhere(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command]
+#[description = "Uses the default contexts"]
+async fn default(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+/// A function returning a command, as given to the framework builder.
+type CommandFn = fn() -> Command<(), (), DefaultError>;
+
+/// Finds the command with the given name in the given commands.
+fn find<'a>(commands: &'a [Value], name: &str) -> &'a Value {
+    commands.iter().find(|command| command["name"] == name).unwrap()
+}
+
+#[tokio::test]
+async fn export_matches_registration() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(everywhere)
+        .command(default)
+        .build();
+
+    let exported = serde_json::from_str::<Vec<Value>>(&framework.export_commands()).unwrap();
+
+    let everywhere = find(&exported, "everywhere");
+    assert_eq!(everywhere["integration_types"], json!([0, 1]));
+    assert_eq!(everywhere["contexts"], json!([0, 1, 2]));
+
+    let default = find(&exported, "default");
+    assert!(default.get("integration_types").is_none());
+    assert!(default.get("contexts").is_none());
+
+    framework.register_global_commands().await.unwrap();
+    let registered = server.requests()
+        .iter()
+        .filter_map(|request| request.json())
+        .collect::<Vec<_>>();
+
+    for name in ["everywhere", "default"] {
+        for field in ["integration_types", "contexts"] {
+            assert_eq!(find(&exported, name).get(field), find(&registered, name).get(field));
+        }
+    }
+}
+
+#[command]
+#[description = "Comes first alphabetically"]
+async fn alpha(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command(user)]
+async fn audit(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_order_is_stable() {
+    let server = MockServer::start().await.unwrap();
+    let framework = |commands: &[CommandFn]| {
+        let mut builder = Framework::builder(server.client(), Id::new(1), ());
+        for command in commands {
+            builder = builder.command(*command);
+        }
+        builder.build()
+    };
+
+    let exported = framework(&[everywhere, audit, default, alpha]).export_commands();
+    let names = serde_json::from_str::<Vec<Value>>(&exported)
+        .unwrap()
+        .iter()
+        .map(|command| command["name"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, ["alpha", "default", "everywhere", "audit"]);
+    assert_eq!(framework(&[alpha, default, audit, everywhere]).export_commands(), exported);
+}
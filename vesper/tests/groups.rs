@@ -0,0 +1,100 @@
+use serde_json::Value;
+use std::sync::Mutex;
+use vesper::{command::ExecutionState, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{CommandOptionValue, Id};
+
+/// The subcommands executed, along with their arguments.
+type Calls = Mutex<Vec<String>>;
+
+#[command]
+#[description = "Shows the configuration"]
+async fn show(ctx: &SlashContext<Calls>) -> DefaultCommandResult {
+    ctx.data.lock().unwrap().push(String::from("show"));
+    Ok(())
+}
+
+#[command]
+#[description = "Sets whether email notifications are sent"]
+async fn email(
+    ctx: &SlashContext<Calls>,
+    #[description = "Whether notifications are sent"] set: bool
+) -> DefaultCommandResult {
+    ctx.data.lock().unwrap().push(format!("email {set}"));
+    Ok(())
+}
+
+fn framework(server: &MockServer) -> Framework<Calls> {
+    Framework::builder(server.client(), Id::new(1), Mutex::new(Vec::new()))
+        .group(|group| {
+            group.name("config")
+                .description("Manages the configuration")
+                .command(show)
+                .group(|sub| {
+                    sub.name("notifications")
+                        .description("Manages notifications")
+                        .command(email)
+                })
+        })
+        .build()
+}
+
+/// Returns the option with the given name of a registered command or option.
+fn option<'a>(parent: &'a Value, name: &str) -> &'a Value {
+    parent["options"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|option| option["name"] == name)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn mixed_group_is_registered() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    framework.register_global_commands().await.unwrap();
+
+    let config = server.requests()
+        .iter()
+        .filter_map(|request| request.json())
+        .find(|command| command["name"] == "config")
+        .unwrap();
+
+    // Subcommands have type 1, subcommand groups have type 2.
+    assert_eq!(option(&config, "show")["type"], 1);
+
+    let notifications = option(&config, "notifications");
+    assert_eq!(notifications["type"], 2);
+
+    let email = option(notifications, "email");
+    assert_eq!(email["type"], 1);
+    assert_eq!(option(email, "set")["type"], 5);
+}
+
+#[tokio::test]
+async fn mixed_group_dispatches_each_level() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let result = TestInteraction::new("config notifications email")
+        .option("set", CommandOptionValue::Boolean(true))
+        .execute(&framework)
+        .await
+        .unwrap();
+    assert!(matches!(result.state, ExecutionState::CommandFinished));
+
+    let result = TestInteraction::new("config show").execute(&framework).await.unwrap();
+    assert!(matches!(result.state, ExecutionState::CommandFinished));
+
+    assert_eq!(*framework.data.lock().unwrap(), ["email true", "show"]);
+}
+
+#[tokio::test]
+async fn unknown_subcommand_is_not_found() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    assert!(TestInteraction::new("config notifications push").execute(&framework).await.is_none());
+    assert!(framework.data.lock().unwrap().is_empty());
+}
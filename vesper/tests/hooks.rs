@@ -0,0 +1,92 @@
+use std::sync::Mutex;
+use vesper::{command::ExecutionState, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::Id;
+
+/// The names of the hooks and commands executed, in the order they were executed.
+type Calls = Mutex<Vec<&'static str>>;
+
+#[before]
+async fn first(ctx: &mut SlashContext<Calls>, _: &str) -> bool {
+    ctx.data.lock().unwrap().push("first");
+    true
+}
+
+#[before]
+async fn second(ctx: &mut SlashContext<Calls>, _: &str) -> bool {
+    ctx.data.lock().unwrap().push("second");
+    true
+}
+
+#[before]
+async fn gate(ctx: &mut SlashContext<Calls>, command_name: &str) -> bool {
+    ctx.data.lock().unwrap().push("gate");
+    command_name != "blocked"
+}
+
+#[before]
+async fn last(ctx: &mut SlashContext<Calls>, _: &str) -> bool {
+    ctx.data.lock().unwrap().push("last");
+    true
+}
+
+#[after]
+async fn record_output(ctx: &mut SlashContext<Calls>, _: &str, output: Option<&DefaultCommandResult>) {
+    ctx.data.lock().unwrap().push(if output.is_some() { "after output" } else { "after none" });
+}
+
+#[after]
+async fn cleanup(ctx: &mut SlashContext<Calls>, _: &str, output: Option<&DefaultCommandResult>) {
+    ctx.data.lock().unwrap().push(if output.is_some() { "cleanup output" } else { "cleanup none" });
+}
+
+#[command]
+#[description = "Runs after every before hook"]
+async fn open(ctx: &SlashContext<Calls>) -> DefaultCommandResult {
+    ctx.data.lock().unwrap().push("open");
+    Ok(())
+}
+
+#[command]
+#[description = "Is stopped by a before hook"]
+async fn blocked(ctx: &SlashContext<Calls>) -> DefaultCommandResult {
+    ctx.data.lock().unwrap().push("blocked");
+    Ok(())
+}
+
+fn framework(server: &MockServer) -> Framework<Calls> {
+    Framework::builder(server.client(), Id::new(1), Mutex::new(Vec::new()))
+        .before(first)
+        .before(second)
+        .before(gate)
+        .before(last)
+        .after(record_output)
+        .after(cleanup)
+        .command(open)
+        .command(blocked)
+        .build()
+}
+
+#[tokio::test]
+async fn hooks_run_in_the_order_they_were_added() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let result = TestInteraction::new("open").execute(&framework).await.unwrap();
+
+    assert!(matches!(result.state, ExecutionState::CommandFinished));
+    assert_eq!(
+        *framework.data.lock().unwrap(),
+        ["first", "second", "gate", "last", "open", "after output", "cleanup output"]
+    );
+}
+
+#[tokio::test]
+async fn before_hook_returning_false_stops_the_rest() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let result = TestInteraction::new("blocked").execute(&framework).await.unwrap();
+
+    assert!(matches!(result.state, ExecutionState::BeforeHookFailed));
+    assert_eq!(*framework.data.lock().unwrap(), ["first", "second", "gate"]);
+}
@@ -0,0 +1,76 @@
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use vesper::http::{verify_signature, WebhookError};
+
+const TIMESTAMP: &str = "1706725800";
+const BODY: &[u8] = br#"{"type":1}"#;
+
+/// Encodes the given bytes as lowercase hex.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Returns the hex encoded public key and the signature of the given body.
+fn sign(body: &[u8]) -> (String, String) {
+    let pair = Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+    let message = [TIMESTAMP.as_bytes(), body].concat();
+
+    (hex(pair.public_key().as_ref()), hex(pair.sign(&message).as_ref()))
+}
+
+#[test]
+fn valid_signature_is_accepted() {
+    let (key, signature) = sign(BODY);
+
+    assert!(verify_signature(&key, &signature, TIMESTAMP, BODY).is_ok());
+    assert!(verify_signature(&key.to_uppercase(), &signature.to_uppercase(), TIMESTAMP, BODY).is_ok());
+}
+
+#[test]
+fn tampered_request_is_rejected() {
+    let (key, signature) = sign(BODY);
+
+    assert!(matches!(
+        verify_signature(&key, &signature, TIMESTAMP, br#"{"type":2}"#),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        verify_signature(&key, &signature, "1706725801", BODY),
+        Err(WebhookError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn non_hex_signature_is_rejected() {
+    let (key, signature) = sign(BODY);
+
+    // `+f` is accepted by `u8::from_str_radix`, but it isn't hex.
+    for invalid in [format!("+f{}", &signature[2..]), format!("zz{}", &signature[2..])] {
+        assert!(matches!(
+            verify_signature(&key, &invalid, TIMESTAMP, BODY),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    assert!(matches!(
+        verify_signature(&format!("+f{}", &key[2..]), &signature, TIMESTAMP, BODY),
+        Err(WebhookError::InvalidPublicKey)
+    ));
+}
+
+#[test]
+fn wrong_length_signature_or_key_is_rejected() {
+    let (key, signature) = sign(BODY);
+
+    assert!(matches!(
+        verify_signature(&key, &signature[2..], TIMESTAMP, BODY),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        verify_signature(&key, &format!("{}00", signature), TIMESTAMP, BODY),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        verify_signature(&key[2..], &signature, TIMESTAMP, BODY),
+        Err(WebhookError::InvalidPublicKey)
+    ));
+}
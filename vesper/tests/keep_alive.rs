@@ -0,0 +1,40 @@
+use std::time::Duration;
+use vesper::{prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::Id;
+
+#[command]
+#[description = "Takes a while to finish"]
+async fn slow(ctx: &SlashContext<()>) -> DefaultCommandResult {
+    let output = ctx.keep_alive(
+        Duration::from_millis(5),
+        |_| String::from("Still working"),
+        async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            "Done"
+        }
+    ).await?;
+
+    ctx.respond().content(output).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_edits_never_overwrite_the_response() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(slow)
+        .build();
+
+    for _ in 0..10 {
+        server.clear();
+        TestInteraction::new("slow").execute(&framework).await.unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].is_callback());
+        assert_eq!(requests.last().unwrap().content().as_deref(), Some("Done"));
+
+        let contents = server.contents();
+        assert!(contents[..contents.len() - 1].iter().all(|content| content == "Still working"));
+        assert!(contents.len() > 1, "No progress edit was sent");
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+use vesper::{command::{ExecutionState, OutputLocation}, prelude::*, testing::{MockServer, TestInteraction}};
+use vesper::twilight_exports::{CommandOptionValue, Id};
+
+/// The tags received by the command.
+type Tags = Mutex<Vec<String>>;
+
+#[command]
+#[description = "Adds some tags"]
+async fn tag(
+    ctx: &SlashContext<Tags>,
+    #[min_length = 2] #[max_length = 5] #[description = "The tags to add"] tag: Variadic<String, 3>
+) -> DefaultCommandResult {
+    ctx.data.lock().unwrap().extend(tag.into_inner());
+    Ok(())
+}
+
+fn framework(server: &MockServer) -> Framework<Tags> {
+    Framework::builder(server.client(), Id::new(1), Mutex::new(Vec::new()))
+        .command(tag)
+        .build()
+}
+
+#[tokio::test]
+async fn variadic_values_within_bounds_are_accepted() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    let result = TestInteraction::new("tag")
+        .option("tag_1", CommandOptionValue::String(String::from("rust")))
+        .option("tag_2", CommandOptionValue::String(String::from("bots")))
+        .execute(&framework)
+        .await
+        .unwrap();
+
+    assert!(matches!(result.state, ExecutionState::CommandFinished));
+    assert_eq!(*framework.data.lock().unwrap(), ["rust", "bots"]);
+}
+
+#[tokio::test]
+async fn every_variadic_value_is_length_checked() {
+    let server = MockServer::start().await.unwrap();
+    let framework = framework(&server);
+
+    for (name, value) in [("tag_2", "discord"), ("tag_3", "a")] {
+        let result = TestInteraction::new("tag")
+            .option("tag_1", CommandOptionValue::String(String::from("rust")))
+            .option(name, CommandOptionValue::String(String::from(value)))
+            .execute(&framework)
+            .await
+            .unwrap();
+
+        assert!(matches!(result.state, ExecutionState::CommandErrored));
+        let OutputLocation::Present(Err(error)) = &result.output else {
+            panic!("The command output is missing");
+        };
+        assert!(matches!(
+            error.downcast_ref::<ParseError>(),
+            Some(ParseError::OutOfRange { argument_name, .. }) if argument_name == name
+        ));
+    }
+
+    assert!(framework.data.lock().unwrap().is_empty());
+}
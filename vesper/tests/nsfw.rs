@@ -0,0 +1,53 @@
+use vesper::{prelude::*, testing::MockServer};
+use vesper::twilight_exports::Id;
+
+#[command]
+#[nsfw]
+#[description = "Marked using the nsfw attribute"]
+async fn attribute(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command(nsfw)]
+#[description = "Marked using the command attribute"]
+async fn inline(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command]
+#[description = "Not marked as nsfw"]
+async fn safe(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[command]
+#[description = "Marked using the builder"]
+async fn built(_ctx: &SlashContext<()>) -> DefaultCommandResult {
+    Ok(())
+}
+
+#[tokio::test]
+async fn nsfw_flag_is_registered() {
+    let server = MockServer::start().await.unwrap();
+    let framework = Framework::builder(server.client(), Id::new(1), ())
+        .command(attribute)
+        .command(inline)
+        .command(safe)
+        .command(|| built().nsfw(true))
+        .build();
+
+    let registered = framework.register_global_commands().await.unwrap();
+
+    let sent = server.requests()
+        .iter()
+        .filter_map(|request| request.json())
+        .map(|command| (command["name"].as_str().unwrap().to_string(), command["nsfw"].as_bool()))
+        .collect::<Vec<_>>();
+
+    for (name, nsfw) in [("attribute", true), ("inline", true), ("safe", false), ("built", true)] {
+        assert!(sent.contains(&(name.to_string(), Some(nsfw))), "{} was not registered with nsfw = {}", name, nsfw);
+
+        let command = registered.iter().find(|command| command.name == name).unwrap();
+        assert_eq!(command.nsfw, Some(nsfw));
+    }
+}